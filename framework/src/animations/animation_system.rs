@@ -1,6 +1,21 @@
+use bevy::ecs::world::{EntityMutExcept, EntityRefExcept, FilteredEntityRef};
 use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
 use bevy::sprite::MeshMaterial2d;
+use super::asset::{apply_animation_assets, AnimationAsset, AnimationAssetLoader};
+use super::clock::{tick_animation_clock, AnimationClock};
+use super::events::{
+    emit_animation_events, AnimationCompleted, AnimationLooped, AnimationMarker, AnimationStarted,
+};
+use super::keyframe::AnimatableProperty;
+use super::orchestrator::{update_orchestrations, AnimationGroupCompleted, AnimationOrchestrator};
+use super::reflected::{read_field, write_property};
+use super::state_machine::{update_state_machines, AnimationStateChanged};
+use super::stack::{apply_animation_stack_fields, apply_animation_stacks, update_animation_stacks};
+use super::spring::{apply_spring_fields, apply_spring_properties, update_springs, SpringSettled};
 use super::timeline::AnimationTimeline;
+use crate::geometrics::{update_stroke_trims, ShapeMaterial, StrokeTrim};
+use crate::interactions::{DeselectEvent, SelectEvent, StartDragEvent, StopDragEvent};
 
 /// Componente que marca uma entidade como animada
 #[derive(Component)]
@@ -35,53 +50,221 @@ pub struct AnimationPlugin;
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app
+            .add_event::<AnimationStarted>()
+            .add_event::<AnimationCompleted>()
+            .add_event::<AnimationLooped>()
+            .add_event::<AnimationMarker>()
+            .add_event::<AnimationGroupCompleted>()
+            .add_event::<SpringSettled>()
+            .add_event::<AnimationStateChanged>()
+            // Lidos pela máquina de estados; registrados também sem o InteractionsPlugin
+            .add_event::<SelectEvent>()
+            .add_event::<DeselectEvent>()
+            .add_event::<StartDragEvent>()
+            .add_event::<StopDragEvent>()
+            // Trecho visível dos contornos, animável como campo (`StrokeTrim::end`)
+            .register_type::<StrokeTrim>()
+            .init_resource::<AnimationOrchestrator>()
+            .init_resource::<AnimationClock>()
+            .add_systems(PreUpdate, tick_animation_clock)
             .add_systems(Update, update_animations)
-            .add_systems(Update, apply_animation_properties.after(update_animations));
+            .add_systems(Update, emit_animation_events.after(update_animations))
+            .add_systems(Update, update_orchestrations.after(emit_animation_events))
+            .add_systems(Update, capture_start_values.after(update_animations))
+            .add_systems(Update, apply_animation_properties.after(capture_start_values))
+            .add_systems(Update, apply_field_properties.after(apply_animation_properties))
+            .add_systems(Update, update_state_machines.after(apply_field_properties))
+            .add_systems(Update, update_animation_stacks.after(update_state_machines))
+            .add_systems(Update, apply_animation_stacks.after(update_animation_stacks))
+            .add_systems(Update, apply_animation_stack_fields.after(apply_animation_stacks))
+            .add_systems(Update, update_springs.after(apply_animation_stack_fields))
+            .add_systems(Update, apply_spring_properties.after(update_springs))
+            .add_systems(Update, apply_spring_fields.after(apply_spring_properties))
+            .add_systems(PostUpdate, update_stroke_trims);
+    }
+    
+    /// Registra o carregador de timelines depois que todos os plugins foram
+    /// construídos, para não depender da ordem em que o `AssetPlugin` foi adicionado
+    fn finish(&self, app: &mut App) {
+        // Timelines em arquivo precisam do AssetPlugin; sem ele (ex: MinimalPlugins) são ignoradas
+        if !app.world().contains_resource::<AssetServer>() {
+            warn!("AssetPlugin ausente: timelines em arquivo (`AnimationHandle`) não serão carregadas");
+            return;
+        }
+        app.init_asset::<AnimationAsset>()
+            .init_asset_loader::<AnimationAssetLoader>()
+            .add_systems(PreUpdate, apply_animation_assets);
     }
 }
 
 /// Sistema que atualiza todas as animações ativas
 pub fn update_animations(
-    time: Res<Time>,
+    clock: Res<AnimationClock>,
     mut query: Query<&mut AnimationComponent>,
 ) {
-    let delta = time.delta_secs();
+    let delta = clock.delta();
     
     for mut animation in query.iter_mut() {
         animation.timeline.update(delta);
     }
 }
 
+/// Animações com o estado da entidade de onde os valores iniciais são lidos
+type StartValuesQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut AnimationComponent,
+        &'static Transform,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+        Option<&'static MeshMaterial2d<ShapeMaterial>>,
+        EntityRefExcept<'static, AnimationComponent>,
+    ),
+>;
+
+/// Sistema que lê os valores iniciais das animações a partir da entidade
+///
+/// Executado na primeira vez que cada animação é reproduzida, para que ela
+/// comece do `Transform`, da cor e dos campos refletidos atuais em vez de
+/// valores padrão.
+pub fn capture_start_values(
+    mut query: StartValuesQuery,
+    materials: Option<Res<Assets<ColorMaterial>>>,
+    shape_materials: Option<Res<Assets<ShapeMaterial>>>,
+    registry: Res<AppTypeRegistry>,
+) {
+    let registry = registry.read();
+    
+    for (mut animation, transform, material_component, shape_material_component, entity) in query.iter_mut() {
+        if animation.timeline.start_values_resolved || !animation.timeline.is_active() {
+            continue;
+        }
+        
+        let color = entity_color(
+            material_component,
+            shape_material_component,
+            materials.as_deref(),
+            shape_materials.as_deref(),
+        );
+        
+        animation.timeline.resolve_start_values(|property| {
+            read_entity_property(property, transform, color, &entity, &registry)
+        });
+    }
+}
+
 /// Sistema que aplica as propriedades animadas às entidades
 pub fn apply_animation_properties(
     mut query: Query<(
         &AnimationComponent,
         &mut Transform,
         Option<&MeshMaterial2d<ColorMaterial>>,
+        Option<&MeshMaterial2d<ShapeMaterial>>,
     )>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut materials: Option<ResMut<Assets<ColorMaterial>>>,
+    mut shape_materials: Option<ResMut<Assets<ShapeMaterial>>>,
 ) {
-    for (animation, mut transform, material_component) in query.iter_mut() {
-        if !animation.auto_apply || !animation.timeline.is_playing() {
+    for (animation, mut transform, material_component, shape_material_component) in query.iter_mut() {
+        if !animation.auto_apply || !animation.timeline.is_active() {
             continue;
         }
         
-        let values = animation.timeline.get_current_values();
-        
-        // Aplicar propriedades ao Transform
-        for (_, property) in values.iter() {
-            property.apply_to_transform(&mut transform);
-            
-            // Aplicar propriedades ao material se existir
-            if let Some(material_component) = material_component {
-                if let Some(material) = materials.get_mut(&material_component.0) {
-                    property.apply_to_material(material);
-                }
-            }
+        animation.timeline.for_each_current_value(|property| {
+            apply_entity_property(
+                &property,
+                &mut transform,
+                material_component,
+                shape_material_component,
+                materials.as_deref_mut(),
+                shape_materials.as_deref_mut(),
+            );
+        });
+    }
+}
+
+/// Cor atual do material da entidade (`ColorMaterial` ou `ShapeMaterial`)
+pub(crate) fn entity_color(
+    material_component: Option<&MeshMaterial2d<ColorMaterial>>,
+    shape_material_component: Option<&MeshMaterial2d<ShapeMaterial>>,
+    materials: Option<&Assets<ColorMaterial>>,
+    shape_materials: Option<&Assets<ShapeMaterial>>,
+) -> Option<Color> {
+    material_component
+        .zip(materials)
+        .and_then(|(m, assets)| assets.get(&m.0))
+        .map(|m| m.color)
+        .or_else(|| {
+            shape_material_component
+                .zip(shape_materials)
+                .and_then(|(m, assets)| assets.get(&m.0))
+                .map(|m| m.color)
+        })
+}
+
+/// Lê o valor atual de uma propriedade na entidade (campos por reflexão)
+pub(crate) fn read_entity_property<'a>(
+    property: &AnimatableProperty,
+    transform: &Transform,
+    color: Option<Color>,
+    entity: impl Into<FilteredEntityRef<'a>>,
+    registry: &TypeRegistry,
+) -> Option<AnimatableProperty> {
+    match property {
+        AnimatableProperty::Field(path, value) => read_field(entity, registry, path, value)
+            .map(|value| AnimatableProperty::Field(path.clone(), value)),
+        _ => property.read_current(transform, color),
+    }
+}
+
+/// Aplica uma propriedade ao `Transform` e ao material da entidade
+///
+/// Campos refletidos (`Field`) são ignorados; eles são aplicados por sistemas
+/// com acesso exclusivo à entidade.
+pub(crate) fn apply_entity_property(
+    property: &AnimatableProperty,
+    transform: &mut Transform,
+    material_component: Option<&MeshMaterial2d<ColorMaterial>>,
+    shape_material_component: Option<&MeshMaterial2d<ShapeMaterial>>,
+    materials: Option<&mut Assets<ColorMaterial>>,
+    shape_materials: Option<&mut Assets<ShapeMaterial>>,
+) {
+    property.apply_to_transform(transform);
+    
+    // Aplicar propriedades ao material se existir
+    if let (Some(material_component), Some(materials)) = (material_component, materials) {
+        if let Some(material) = materials.get_mut(&material_component.0) {
+            property.apply_to_material(material);
+        }
+    }
+    
+    if let (Some(shape_material_component), Some(shape_materials)) =
+        (shape_material_component, shape_materials)
+    {
+        if let Some(material) = shape_materials.get_mut(&shape_material_component.0) {
+            property.apply_to_shape_material(material);
         }
     }
 }
 
+/// Sistema que aplica os campos animados por reflexão (`Field` e `Custom` com caminho)
+pub fn apply_field_properties(
+    mut query: Query<(&AnimationComponent, EntityMutExcept<AnimationComponent>)>,
+    registry: Res<AppTypeRegistry>,
+) {
+    let registry = registry.read();
+    
+    for (animation, entity) in query.iter_mut() {
+        if !animation.auto_apply || !animation.timeline.is_active() {
+            continue;
+        }
+        
+        // Inclui as tracks procedurais de campos, com ou sem track de keyframes
+        animation.timeline.for_each_current_value(|property| {
+            write_property(&entity, &registry, &property);
+        });
+    }
+}
+
 /// Sistema auxiliar para controlar animações via eventos
 #[derive(Event)]
 pub enum AnimationEvent {
@@ -0,0 +1,295 @@
+//! Timelines serializáveis e carregadas como assets
+//!
+//! Uma `TimelineDefinition` contém apenas os dados da animação (tracks,
+//! keyframes, easings, modo, velocidade, delay e marcadores) e pode ser salva
+//! em RON ou JSON. Arquivos `.anim.ron` e `.anim.json` são carregados pelo
+//! `AssetServer` como `AnimationAsset`.
+//!
+//! Com o recurso `file_watcher` do Bevy ativo (feature `hot_reload` do
+//! framework), alterações no arquivo são aplicadas às entidades sem reiniciar a
+//! animação.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! // assets/animations/bounce.anim.ron
+//! (
+//!     name: "bounce",
+//!     mode: Loop,
+//!     tracks: [
+//!         (
+//!             name: "position",
+//!             keyframes: [
+//!                 (time: 0.0, property: Position((0.0, 0.0)), source: Current),
+//!                 (time: 0.5, property: Position((0.0, 80.0)), easing: EaseOutQuad, source: Relative(Position((0.0, 80.0)))),
+//!                 (time: 1.0, property: Position((0.0, 0.0)), easing: EaseInQuad, source: Relative(Position((0.0, -80.0)))),
+//!             ],
+//!         ),
+//!     ],
+//! )
+//!
+//! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     commands.spawn((
+//!         Geometrics::circle(...),
+//!         AnimationHandle(asset_server.load("animations/bounce.anim.ron")),
+//!     ));
+//! }
+//! ```
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use super::animation_system::AnimationComponent;
+use super::keyframe::KeyframeTrack;
+use super::procedural::ProceduralTrack;
+use super::timeline::{AnimationMode, AnimationTimeline};
+
+/// Dados serializáveis de uma timeline (sem estado de reprodução nem callbacks)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineDefinition {
+    /// Nome da animação
+    pub name: String,
+    /// Tracks de propriedades
+    #[serde(default)]
+    pub tracks: Vec<KeyframeTrack>,
+    /// Modo de execução
+    #[serde(default)]
+    pub mode: AnimationMode,
+    /// Velocidade de reprodução
+    #[serde(default = "default_speed")]
+    pub speed: f32,
+    /// Delay inicial em segundos
+    #[serde(default)]
+    pub delay: f32,
+    /// Marcadores nomeados (tempo, nome)
+    #[serde(default)]
+    pub markers: Vec<(f32, String)>,
+    /// Tracks procedurais (ruído e osciladores)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub procedural: Vec<ProceduralTrack>,
+}
+
+fn default_speed() -> f32 {
+    1.0
+}
+
+impl TimelineDefinition {
+    /// Lê uma definição em RON
+    pub fn from_ron(text: &str) -> Result<Self, AnimationAssetError> {
+        Ok(ron::de::from_str(text)?)
+    }
+
+    /// Escreve a definição em RON formatado
+    pub fn to_ron(&self) -> Result<String, AnimationAssetError> {
+        Ok(ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?)
+    }
+
+    /// Lê uma definição em JSON
+    pub fn from_json(text: &str) -> Result<Self, AnimationAssetError> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Escreve a definição em JSON formatado
+    pub fn to_json(&self) -> Result<String, AnimationAssetError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl AnimationTimeline {
+    /// Extrai os dados serializáveis da timeline
+    pub fn to_definition(&self) -> TimelineDefinition {
+        let mut tracks: Vec<KeyframeTrack> = self.tracks.values().cloned().collect();
+        tracks.sort_by(|a, b| a.name.cmp(&b.name));
+
+        TimelineDefinition {
+            name: self.name.clone(),
+            tracks,
+            mode: self.mode,
+            speed: self.speed,
+            delay: self.delay,
+            markers: self.markers.clone(),
+            procedural: self.procedural.clone(),
+        }
+    }
+
+    /// Cria uma timeline parada a partir de uma definição
+    pub fn from_definition(definition: &TimelineDefinition) -> Self {
+        let mut timeline = AnimationTimeline::new(definition.name.clone());
+        timeline.apply_definition(definition);
+        timeline
+    }
+
+    /// Substitui os dados da timeline mantendo o estado de reprodução e os callbacks
+    ///
+    /// Os valores iniciais já lidos da entidade são reaproveitados pelas tracks
+    /// de mesmo nome, para que a animação não salte ao ser recarregada.
+    pub fn apply_definition(&mut self, definition: &TimelineDefinition) {
+        let mut tracks = std::collections::HashMap::new();
+        for track in &definition.tracks {
+            let mut track = track.clone();
+            track.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+            let previous_start = self
+                .tracks
+                .get(&track.name)
+                .and_then(|t| t.keyframes.first())
+                .map(|k| k.property.clone());
+            track.resolve(previous_start.as_ref());
+
+            tracks.insert(track.name.clone(), track);
+        }
+
+        self.name = definition.name.clone();
+        self.tracks = tracks;
+        self.mode = definition.mode;
+        self.speed = definition.speed;
+        self.delay = definition.delay.max(0.0);
+        self.markers = definition.markers.clone();
+        self.markers.sort_by(|a, b| a.0.total_cmp(&b.0));
+        
+        // Manter os valores iniciais já lidos da entidade
+        let bases: Vec<_> = self.procedural.iter().map(|track| track.base.clone()).collect();
+        self.procedural = definition.procedural.clone();
+        for (track, base) in self.procedural.iter_mut().zip(bases) {
+            track.base = base;
+        }
+        self.update_duration();
+        self.elapsed_time = self.elapsed_time.clamp(0.0, self.duration);
+    }
+}
+
+/// Timeline carregada de um arquivo `.anim.ron` ou `.anim.json`
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct AnimationAsset(pub TimelineDefinition);
+
+/// Erro ao ler ou escrever uma timeline
+#[derive(Debug)]
+pub enum AnimationAssetError {
+    /// Falha ao ler o arquivo
+    Io(std::io::Error),
+    /// RON inválido
+    Ron(ron::Error),
+    /// JSON inválido
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for AnimationAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationAssetError::Io(error) => write!(f, "erro ao ler animação: {error}"),
+            AnimationAssetError::Ron(error) => write!(f, "animação RON inválida: {error}"),
+            AnimationAssetError::Json(error) => write!(f, "animação JSON inválida: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for AnimationAssetError {}
+
+impl From<std::io::Error> for AnimationAssetError {
+    fn from(error: std::io::Error) -> Self {
+        AnimationAssetError::Io(error)
+    }
+}
+
+impl From<ron::Error> for AnimationAssetError {
+    fn from(error: ron::Error) -> Self {
+        AnimationAssetError::Ron(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for AnimationAssetError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        AnimationAssetError::Ron(error.code)
+    }
+}
+
+impl From<serde_json::Error> for AnimationAssetError {
+    fn from(error: serde_json::Error) -> Self {
+        AnimationAssetError::Json(error)
+    }
+}
+
+/// Carregador de `AnimationAsset` (RON ou JSON, pela extensão do arquivo)
+#[derive(Default)]
+pub struct AnimationAssetLoader;
+
+impl AssetLoader for AnimationAssetLoader {
+    type Asset = AnimationAsset;
+    type Settings = ();
+    type Error = AnimationAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let text = String::from_utf8_lossy(&bytes);
+
+        let is_json = load_context
+            .path()
+            .extension()
+            .is_some_and(|extension| extension == "json");
+        let definition = if is_json {
+            TimelineDefinition::from_json(&text)?
+        } else {
+            TimelineDefinition::from_ron(&text)?
+        };
+
+        Ok(AnimationAsset(definition))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["anim.ron", "anim.json"]
+    }
+}
+
+/// Anima a entidade com uma timeline carregada como asset
+///
+/// Quando o asset termina de carregar, um `AnimationComponent` é inserido e a
+/// animação começa. Se o arquivo for alterado, a timeline é atualizada
+/// mantendo o tempo atual.
+#[derive(Component, Debug, Clone)]
+pub struct AnimationHandle(pub Handle<AnimationAsset>);
+
+/// Sistema que cria e recarrega as animações das entidades com `AnimationHandle`
+pub fn apply_animation_assets(
+    mut commands: Commands,
+    mut asset_events: EventReader<AssetEvent<AnimationAsset>>,
+    assets: Res<Assets<AnimationAsset>>,
+    mut query: Query<(Entity, Ref<AnimationHandle>, Option<&mut AnimationComponent>)>,
+) {
+    let mut loaded = Vec::new();
+    let mut modified = Vec::new();
+    for event in asset_events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } => loaded.push(*id),
+            AssetEvent::Modified { id } => modified.push(*id),
+            _ => {}
+        }
+    }
+
+    for (entity, handle, animation) in query.iter_mut() {
+        let id = handle.0.id();
+        let Some(asset) = assets.get(id) else {
+            continue;
+        };
+
+        match animation {
+            // Arquivo alterado: atualizar mantendo o estado de reprodução
+            Some(mut animation) if modified.contains(&id) && !handle.is_changed() => {
+                animation.timeline.apply_definition(&asset.0);
+            }
+            // Handle novo ou asset recém-carregado: criar a animação
+            animation if handle.is_changed() || (animation.is_none() && loaded.contains(&id)) => {
+                let mut timeline = AnimationTimeline::from_definition(&asset.0);
+                timeline.play();
+                commands.entity(entity).insert(AnimationComponent::new(timeline));
+            }
+            _ => {}
+        }
+    }
+}
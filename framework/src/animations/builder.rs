@@ -1,12 +1,28 @@
 use bevy::prelude::*;
 use super::timeline::{AnimationTimeline, AnimationMode};
-use super::keyframe::{Keyframe, AnimatableProperty};
+use super::keyframe::{Keyframe, KeyframeTrack, AnimatableProperty, RotationMode};
+use super::color_space::ColorSpace;
 use super::easing::Easing;
+use super::path::{MotionPath, PathFollow};
+use super::procedural::ProceduralTrack;
+use super::reflected::FieldValue;
+use std::collections::HashMap;
 
 /// Builder para criar animações de forma fluente
+///
+/// Por padrão cada passo começa quando o anterior termina. Use `parallel`,
+/// `sequence` e `at` para agrupar passos concorrentes ou posicionar o cursor.
 pub struct AnimationBuilder {
     timeline: AnimationTimeline,
     current_time: f32,
+    /// Se os passos atuais começam todos no início do grupo
+    parallel: bool,
+    /// Tempo de início do grupo paralelo atual
+    group_start: f32,
+    /// Maior tempo de término entre os passos do grupo paralelo atual
+    group_end: f32,
+    /// Tempo de início do primeiro passo de cada track criada por um passo
+    track_starts: HashMap<String, f32>,
 }
 
 impl AnimationBuilder {
@@ -15,6 +31,10 @@ impl AnimationBuilder {
         Self {
             timeline: AnimationTimeline::new(name.into()),
             current_time: 0.0,
+            parallel: false,
+            group_start: 0.0,
+            group_end: 0.0,
+            track_starts: HashMap::new(),
         }
     }
     
@@ -27,8 +47,7 @@ impl AnimationBuilder {
     
     /// Adiciona uma animação de movimento para uma posição
     pub fn move_to(mut self, position: Vec2, duration: f32, easing: Easing) -> Self {
-        let start_time = self.current_time;
-        self.current_time += duration;
+        let start_time = self.begin_step("position", duration);
         
         self.timeline.add_keyframe(
             "position",
@@ -38,26 +57,13 @@ impl AnimationBuilder {
         self
     }
     
-    /// Adiciona uma animação de movimento relativo
+    /// Adiciona uma animação de movimento relativo à posição anterior
     pub fn move_by(mut self, delta: Vec2, duration: f32, easing: Easing) -> Self {
-        // Nota: movimento relativo requer conhecer a posição inicial
-        // Por simplicidade, vamos assumir que a posição inicial é Vec2::ZERO
-        // Em uma implementação mais completa, isso deveria ser tratado diferentemente
-        let start_time = self.current_time;
-        self.current_time += duration;
-        
-        // Adicionar keyframe inicial se for o primeiro
-        if start_time == 0.0 {
-            self.timeline.add_keyframe(
-                "position",
-                Keyframe::position(0.0, Vec2::ZERO, Easing::Linear)
-            );
-        }
+        let start_time = self.begin_step("position", duration);
         
-        // Posição é relativa ao último keyframe
         self.timeline.add_keyframe(
             "position",
-            Keyframe::position(start_time + duration, delta, easing)
+            Keyframe::relative(start_time + duration, AnimatableProperty::Position(delta), easing)
         );
         
         self
@@ -67,8 +73,7 @@ impl AnimationBuilder {
     
     /// Adiciona uma animação de rotação para um ângulo específico
     pub fn rotate_to(mut self, angle: f32, duration: f32, easing: Easing) -> Self {
-        let start_time = self.current_time;
-        self.current_time += duration;
+        let start_time = self.begin_step("rotation", duration);
         
         self.timeline.add_keyframe(
             "rotation",
@@ -78,27 +83,52 @@ impl AnimationBuilder {
         self
     }
     
-    /// Adiciona uma animação de rotação relativa
+    /// Adiciona uma animação de rotação com um modo de rotação
+    ///
+    /// ```rust
+    /// // De 350° para 10° girando apenas 20°
+    /// AnimationBuilder::new("dial")
+    ///     .rotate_to_with(10f32.to_radians(), RotationMode::Shortest, 0.3, Easing::EaseOut)
+    ///     .build();
+    /// ```
+    pub fn rotate_to_with(mut self, angle: f32, mode: RotationMode, duration: f32, easing: Easing) -> Self {
+        let start_time = self.begin_step("rotation", duration);
+        
+        self.timeline.add_keyframe(
+            "rotation",
+            Keyframe::rotation_with(start_time + duration, angle, mode, easing)
+        );
+        
+        self
+    }
+    
+    /// Adiciona uma animação de rotação relativa ao ângulo anterior
+    ///
+    /// O delta é sempre percorrido por inteiro, independente do modo da track.
     pub fn rotate_by(mut self, delta_angle: f32, duration: f32, easing: Easing) -> Self {
-        let start_time = self.current_time;
-        self.current_time += duration;
-        
-        // Adicionar keyframe inicial se for o primeiro
-        if start_time == 0.0 {
-            self.timeline.add_keyframe(
-                "rotation",
-                Keyframe::rotation(0.0, 0.0, Easing::Linear)
-            );
-        }
+        let start_time = self.begin_step("rotation", duration);
         
         self.timeline.add_keyframe(
             "rotation",
-            Keyframe::rotation(start_time + duration, delta_angle, easing)
+            Keyframe {
+                rotation_mode: Some(RotationMode::Absolute),
+                ..Keyframe::relative(start_time + duration, AnimatableProperty::Rotation(delta_angle), easing)
+            }
         );
         
         self
     }
     
+    /// Define o modo de rotação padrão da track de rotação
+    pub fn rotation_mode(mut self, mode: RotationMode) -> Self {
+        self.timeline
+            .tracks
+            .entry("rotation".to_string())
+            .or_insert_with(|| KeyframeTrack::new("rotation".to_string()))
+            .rotation_mode = mode;
+        self
+    }
+    
     // === Métodos de escala ===
     
     /// Adiciona uma animação de escala uniforme
@@ -108,8 +138,7 @@ impl AnimationBuilder {
     
     /// Adiciona uma animação de escala não uniforme
     pub fn scale_to_xy(mut self, scale: Vec2, duration: f32, easing: Easing) -> Self {
-        let start_time = self.current_time;
-        self.current_time += duration;
+        let start_time = self.begin_step("scale", duration);
         
         self.timeline.add_keyframe(
             "scale",
@@ -123,8 +152,7 @@ impl AnimationBuilder {
     
     /// Adiciona uma animação de mudança de cor
     pub fn color_to(mut self, color: Color, duration: f32, easing: Easing) -> Self {
-        let start_time = self.current_time;
-        self.current_time += duration;
+        let start_time = self.begin_step("color", duration);
         
         self.timeline.add_keyframe(
             "color",
@@ -134,10 +162,38 @@ impl AnimationBuilder {
         self
     }
     
+    /// Adiciona uma animação de mudança de cor interpolada no espaço de cor dado
+    ///
+    /// ```rust
+    /// // Vermelho para azul passando por magenta, sem escurecer no meio
+    /// AnimationBuilder::new("hue")
+    ///     .color_to_in(Color::srgb(0.0, 0.0, 1.0), ColorSpace::Oklch, 1.0, Easing::Linear)
+    ///     .build();
+    /// ```
+    pub fn color_to_in(mut self, color: Color, color_space: ColorSpace, duration: f32, easing: Easing) -> Self {
+        let start_time = self.begin_step("color", duration);
+        
+        self.timeline.add_keyframe(
+            "color",
+            Keyframe::color_in(start_time + duration, color, color_space, easing)
+        );
+        
+        self
+    }
+    
+    /// Define o espaço de cor padrão da track de cor
+    pub fn color_space(mut self, color_space: ColorSpace) -> Self {
+        self.timeline
+            .tracks
+            .entry("color".to_string())
+            .or_insert_with(|| KeyframeTrack::new("color".to_string()))
+            .color_space = color_space;
+        self
+    }
+    
     /// Adiciona uma animação de fade (opacidade)
     pub fn fade_to(mut self, opacity: f32, duration: f32, easing: Easing) -> Self {
-        let start_time = self.current_time;
-        self.current_time += duration;
+        let start_time = self.begin_step("opacity", duration);
         
         self.timeline.add_keyframe(
             "opacity",
@@ -157,14 +213,234 @@ impl AnimationBuilder {
         self.fade_to(0.0, duration, easing)
     }
     
+    // === Métodos de caminho ===
+    
+    /// Move a entidade ao longo de um caminho com velocidade constante
+    ///
+    /// O easing é aplicado sobre o comprimento do caminho. Passos seguidos de
+    /// `follow_path` percorrem os caminhos em sequência.
+    pub fn follow_path(self, path: MotionPath, duration: f32, easing: Easing) -> Self {
+        self.follow(PathFollow::new(path), duration, easing)
+    }
+    
+    /// Move a entidade ao longo de um caminho girando-a para a tangente
+    ///
+    /// `rotation_offset` (radianos) é somado ao ângulo da tangente.
+    pub fn follow_path_rotated(
+        self,
+        path: MotionPath,
+        rotation_offset: f32,
+        duration: f32,
+        easing: Easing,
+    ) -> Self {
+        self.follow(PathFollow::rotated(path, rotation_offset), duration, easing)
+    }
+    
+    fn follow(mut self, follow: PathFollow, duration: f32, easing: Easing) -> Self {
+        let start_time = self.begin_step("path", duration);
+        let index = self.timeline.tracks.get("path").map_or(0, |t| t.paths.len());
+        
+        self.timeline.add_keyframe(
+            "path",
+            Keyframe::new(start_time + duration, AnimatableProperty::PathProgress(index as f32 + 1.0), easing)
+        );
+        self.timeline.tracks.get_mut("path").unwrap().paths.push(follow);
+        
+        self
+    }
+    
+    // === Métodos de campos refletidos ===
+    
+    /// Anima um campo de qualquer componente registrado para reflexão
+    ///
+    /// `path` tem o formato `Componente::campo` (ex: `"MyGauge::value"`,
+    /// `"Sprite::color"`). O valor inicial é lido da entidade.
+    pub fn animate_field(
+        mut self,
+        path: impl Into<String>,
+        value: impl Into<FieldValue>,
+        duration: f32,
+        easing: Easing,
+    ) -> Self {
+        let path = path.into();
+        let start_time = self.begin_step(&path, duration);
+        
+        self.timeline.add_keyframe(
+            &path,
+            Keyframe::field(start_time + duration, path.clone(), value, easing)
+        );
+        
+        self
+    }
+    
+    /// Desenha o contorno aos poucos, levando o fim do trim até 1.0
+    ///
+    /// A entidade precisa de um `StrokeTrim` (ex: `StrokeTrim::new(0.0, 0.0)`).
+    pub fn draw_on(self, duration: f32, easing: Easing) -> Self {
+        self.animate_field("StrokeTrim::end", 1.0, duration, easing)
+    }
+    
+    /// Apaga o contorno aos poucos, levando o início do trim até 1.0
+    ///
+    /// A entidade precisa de um `StrokeTrim`.
+    pub fn erase(self, duration: f32, easing: Easing) -> Self {
+        self.animate_field("StrokeTrim::start", 1.0, duration, easing)
+    }
+    
+    // === Métodos procedurais ===
+    
+    /// Soma uma track procedural (ruído ou oscilador) a uma propriedade
+    ///
+    /// A track começa no tempo atual do cursor (mais o seu `start`); se tiver
+    /// duração, o cursor avança como em um passo comum.
+    pub fn procedural(mut self, mut track: ProceduralTrack) -> Self {
+        track.start += self.cursor();
+        let duration = track.duration;
+        self.timeline.add_procedural(track);
+        
+        match duration {
+            Some(duration) => self.wait(duration),
+            None => self,
+        }
+    }
+    
     // === Métodos de tempo ===
     
     /// Adiciona um delay/pausa na animação
+    ///
+    /// Dentro de `parallel`, apenas estende a duração do grupo.
     pub fn wait(mut self, duration: f32) -> Self {
-        self.current_time += duration;
+        if self.parallel {
+            self.group_end = self.group_end.max(self.group_start + duration);
+        } else {
+            self.current_time += duration;
+        }
         self
     }
     
+    /// Adiciona um marcador nomeado no tempo atual do cursor
+    ///
+    /// Quando a reprodução passa pelo marcador, o `AnimationPlugin` emite um
+    /// evento `AnimationMarker` com o nome.
+    pub fn marker(mut self, name: impl Into<String>) -> Self {
+        let time = self.cursor();
+        self.timeline.add_marker(time, name);
+        self
+    }
+    
+    /// Adiciona um marcador nomeado em um tempo absoluto da timeline
+    pub fn marker_at(mut self, time: f32, name: impl Into<String>) -> Self {
+        self.timeline.add_marker(time, name);
+        self
+    }
+    
+    // === Métodos de agrupamento ===
+    
+    /// Executa os passos do grupo ao mesmo tempo
+    ///
+    /// Todos os passos começam no tempo atual e o cursor avança pela duração do
+    /// passo mais longo.
+    ///
+    /// ```rust
+    /// AnimationBuilder::new("move_and_spin")
+    ///     .parallel(|b| b
+    ///         .move_to(Vec2::new(100.0, 0.0), 1.0, Easing::EaseInOut)
+    ///         .rotate_to(PI, 2.0, Easing::Linear))
+    ///     .fade_out(0.5, Easing::EaseOut) // começa em t = 2.0
+    ///     .build();
+    /// ```
+    pub fn parallel(mut self, f: impl FnOnce(Self) -> Self) -> Self {
+        let saved = (self.parallel, self.group_start, self.group_end);
+        let start = self.cursor();
+        
+        self.parallel = true;
+        self.group_start = start;
+        self.group_end = start;
+        self.current_time = start;
+        
+        let mut builder = f(self);
+        let end = builder.group_end;
+        (builder.parallel, builder.group_start, builder.group_end) = saved;
+        builder.end_group(end);
+        builder
+    }
+    
+    /// Executa os passos do grupo um após o outro
+    ///
+    /// Útil dentro de `parallel` para criar uma cadeia que roda junto com
+    /// outros passos; o grupo conta como um único passo para o pai.
+    pub fn sequence(mut self, f: impl FnOnce(Self) -> Self) -> Self {
+        let saved = (self.parallel, self.group_start, self.group_end);
+        let start = self.cursor();
+        
+        self.parallel = false;
+        self.current_time = start;
+        
+        let mut builder = f(self);
+        let end = builder.current_time;
+        (builder.parallel, builder.group_start, builder.group_end) = saved;
+        builder.end_group(end);
+        builder
+    }
+    
+    /// Move o cursor para um tempo absoluto da timeline
+    ///
+    /// Os próximos passos começam em `time`, mesmo que isso sobreponha passos
+    /// anteriores.
+    pub fn at(mut self, time: f32) -> Self {
+        let time = time.max(0.0);
+        if self.parallel {
+            self.group_start = time;
+        }
+        self.current_time = time;
+        self
+    }
+    
+    /// Tempo em que o próximo passo começa
+    fn cursor(&self) -> f32 {
+        if self.parallel {
+            self.group_start
+        } else {
+            self.current_time
+        }
+    }
+    
+    /// Finaliza um grupo filho que terminou em `end`
+    fn end_group(&mut self, end: f32) {
+        if self.parallel {
+            self.group_end = self.group_end.max(end);
+            self.current_time = self.group_start;
+        } else {
+            self.current_time = end;
+        }
+    }
+    
+    /// Reserva o intervalo de um passo na track e retorna seu tempo inicial
+    ///
+    /// Se a track já tem keyframes antes do início do passo, o último valor é
+    /// mantido até lá para que a transição comece no tempo certo.
+    fn begin_step(&mut self, track_name: &str, duration: f32) -> f32 {
+        let start_time = self.cursor();
+        if self.parallel {
+            self.group_end = self.group_end.max(start_time + duration);
+        } else {
+            self.current_time = start_time + duration;
+        }
+        
+        match self.timeline.tracks.get(track_name).and_then(|t| t.keyframes.last()) {
+            Some(last) if last.time < start_time => {
+                let hold = Keyframe::hold(start_time, last.property.clone());
+                self.timeline.add_keyframe(track_name, hold);
+            }
+            Some(_) => {}
+            None => {
+                self.track_starts.entry(track_name.to_string()).or_insert(start_time);
+            }
+        }
+        
+        start_time
+    }
+    
     /// Define um delay inicial antes da animação começar
     pub fn with_delay(mut self, delay: f32) -> Self {
         self.timeline.set_delay(delay);
@@ -194,18 +470,28 @@ impl AnimationBuilder {
         self.with_mode(AnimationMode::PingPong)
     }
     
-    /// Define a velocidade de reprodução
+    /// Define a velocidade de reprodução (negativa para reproduzir ao contrário)
     pub fn with_speed(mut self, speed: f32) -> Self {
         self.timeline.set_speed(speed);
         self
     }
     
+    /// Faz a timeline ser controlada externamente (por `seek`) em vez do tempo
+    pub fn driven_externally(mut self) -> Self {
+        self.timeline.set_driven_externally(true);
+        self
+    }
+    
     // === Métodos de construção personalizada ===
     
     /// Adiciona um keyframe personalizado
     pub fn add_keyframe(mut self, track_name: &str, time: f32, property: AnimatableProperty, easing: Easing) -> Self {
         self.timeline.add_keyframe(track_name, Keyframe::new(time, property, easing));
-        self.current_time = self.current_time.max(time);
+        if self.parallel {
+            self.group_end = self.group_end.max(time);
+        } else {
+            self.current_time = self.current_time.max(time);
+        }
         self
     }
     
@@ -240,10 +526,10 @@ impl AnimationBuilder {
     /// Constrói e retorna a timeline final
     pub fn build(mut self) -> AnimationTimeline {
         // Adicionar keyframes iniciais para propriedades que não têm
-        // um keyframe no tempo 0
+        // um keyframe no tempo 0. O valor é lido da entidade quando a
+        // animação começa; o valor padrão só é usado sem entidade.
         for (track_name, track) in self.timeline.tracks.clone() {
             if !track.keyframes.is_empty() && track.keyframes[0].time > 0.0 {
-                // Adicionar keyframe inicial com valor padrão
                 let initial_property = match track.keyframes[0].property {
                     AnimatableProperty::Position(_) => AnimatableProperty::Position(Vec2::ZERO),
                     AnimatableProperty::Rotation(_) => AnimatableProperty::Rotation(0.0),
@@ -251,13 +537,29 @@ impl AnimationBuilder {
                     AnimatableProperty::Color(_) => AnimatableProperty::Color(Color::WHITE),
                     AnimatableProperty::Opacity(_) => AnimatableProperty::Opacity(1.0),
                     AnimatableProperty::Custom(ref name, _) => AnimatableProperty::Custom(name.clone(), 0.0),
+                    AnimatableProperty::Field(ref path, value) => AnimatableProperty::Field(path.clone(), value.neutral()),
+                    AnimatableProperty::PathProgress(_) => AnimatableProperty::PathProgress(0.0),
                 };
                 
-                self.timeline.tracks.get_mut(&track_name).unwrap()
-                    .keyframes.insert(0, Keyframe::new(0.0, initial_property, Easing::Linear));
+                let keyframes = &mut self.timeline.tracks.get_mut(&track_name).unwrap().keyframes;
+                
+                // Manter o valor inicial até o primeiro passo da track começar
+                if let Some(&start) = self.track_starts.get(&track_name) {
+                    if start > 0.0 && start < keyframes[0].time {
+                        keyframes.insert(0, Keyframe::hold(start, initial_property.clone()));
+                    }
+                }
+                
+                keyframes.insert(0, Keyframe::from_current(0.0, initial_property));
             }
         }
         
+        // Resolver keyframes relativos a partir dos valores padrão, para que a
+        // timeline também funcione sem entidade
+        for track in self.timeline.tracks.values_mut() {
+            track.resolve(None);
+        }
+        
         self.timeline
     }
     
@@ -276,8 +578,8 @@ impl AnimationPresets {
     /// Cria uma animação de bounce (salto)
     pub fn bounce(height: f32, duration: f32) -> AnimationTimeline {
         AnimationBuilder::new("bounce")
-            .move_to(Vec2::new(0.0, height), duration / 2.0, Easing::EaseOutQuad)
-            .move_to(Vec2::ZERO, duration / 2.0, Easing::EaseInQuad)
+            .move_by(Vec2::new(0.0, height), duration / 2.0, Easing::EaseOutQuad)
+            .move_by(Vec2::new(0.0, -height), duration / 2.0, Easing::EaseInQuad)
             .repeat()
             .build()
     }
@@ -285,7 +587,7 @@ impl AnimationPresets {
     /// Cria uma animação de rotação contínua
     pub fn spin(speed: f32) -> AnimationTimeline {
         AnimationBuilder::new("spin")
-            .rotate_to(std::f32::consts::TAU, 1.0 / speed, Easing::Linear)
+            .rotate_by(std::f32::consts::TAU, 1.0 / speed, Easing::Linear)
             .repeat()
             .build()
     }
@@ -305,13 +607,28 @@ impl AnimationPresets {
         let step_duration = duration / steps as f32;
         let mut builder = AnimationBuilder::new("shake");
         
+        // Deslocamentos relativos à posição original da entidade
+        let mut offset = Vec2::ZERO;
         for i in 0..steps {
             let x = if i % 2 == 0 { intensity } else { -intensity };
             let y = if i % 3 == 0 { intensity * 0.5 } else { -intensity * 0.5 };
-            builder = builder.move_to(Vec2::new(x, y), step_duration, Easing::Linear);
+            let target = Vec2::new(x, y);
+            builder = builder.move_by(target - offset, step_duration, Easing::Linear);
+            offset = target;
         }
         
-        builder.move_to(Vec2::ZERO, step_duration, Easing::EaseOut).build()
+        builder.move_by(-offset, step_duration, Easing::EaseOut).build()
+    }
+    
+    /// Cria um tremor suave com ruído, reproduzível pela semente
+    pub fn wiggle(intensity: f32, frequency: f32, duration: f32, seed: u32) -> AnimationTimeline {
+        AnimationBuilder::new("wiggle")
+            .procedural(
+                ProceduralTrack::noise(AnimatableProperty::Position(Vec2::splat(intensity)), frequency, seed)
+                    .lasting(duration)
+                    .decaying(),
+            )
+            .build()
     }
     
     /// Cria uma animação de fade in
@@ -0,0 +1,173 @@
+//! Relógio das animações
+//!
+//! Por padrão as animações avançam com o `Time` do Bevy, que depende do tempo
+//! real entre quadros. Para testes e renderização quadro a quadro, o recurso
+//! `AnimationClock` pode avançar um passo fixo por quadro ou apenas quando
+//! `step` é chamado, tornando a reprodução determinística.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! // Exatamente 1/60 s por quadro, independente do tempo real
+//! app.insert_resource(AnimationClock::fixed(1.0 / 60.0));
+//!
+//! // Controle manual: o tempo só avança com `step`
+//! app.insert_resource(AnimationClock::manual());
+//! app.world_mut().resource_mut::<AnimationClock>().step(0.5);
+//! app.update(); // animações avançam 0.5 s
+//! app.update(); // nada muda
+//! ```
+//!
+//! # Sem janela
+//!
+//! O `AnimationPlugin` funciona só com `MinimalPlugins`: sem `Assets<ColorMaterial>`
+//! as cores não são aplicadas, e sem `AssetPlugin` timelines em arquivo
+//! (`AnimationHandle`) são ignoradas.
+//! ```rust
+//! let mut app = App::new();
+//! app.add_plugins((MinimalPlugins, AnimationPlugin))
+//!     .insert_resource(AnimationClock::manual());
+//! ```
+
+use bevy::prelude::*;
+
+/// Como o relógio das animações avança
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ClockMode {
+    /// Usa o delta do `Time` do Bevy (padrão)
+    #[default]
+    RealTime,
+    /// Avança o mesmo passo (em segundos) a cada quadro
+    FixedStep(f32),
+    /// Avança apenas o tempo pedido com `step`
+    Manual,
+}
+
+/// Recurso com o tempo usado por todos os sistemas de animação
+///
+/// O delta é calculado uma vez por quadro, no `PreUpdate`, e lido pelas
+/// timelines, pilhas de animação e molas.
+#[derive(Resource, Debug, Clone)]
+pub struct AnimationClock {
+    /// Modo do relógio
+    pub mode: ClockMode,
+    /// Multiplicador aplicado ao delta (ex: 0.5 = câmera lenta)
+    pub speed: f32,
+    /// Se o relógio está pausado (delta zero em qualquer modo)
+    pub paused: bool,
+    /// Tempo pedido com `step` e ainda não consumido
+    pending: f32,
+    /// Delta do quadro atual
+    delta: f32,
+    /// Tempo total avançado pelo relógio
+    elapsed: f32,
+    /// Quadros em que o relógio avançou
+    frame: u64,
+}
+
+impl Default for AnimationClock {
+    fn default() -> Self {
+        Self::real_time()
+    }
+}
+
+impl AnimationClock {
+    /// Relógio que segue o tempo real
+    pub fn real_time() -> Self {
+        Self::with_mode(ClockMode::RealTime)
+    }
+
+    /// Relógio que avança `step` segundos por quadro
+    pub fn fixed(step: f32) -> Self {
+        Self::with_mode(ClockMode::FixedStep(step.max(0.0)))
+    }
+
+    /// Relógio que avança apenas com `step`
+    pub fn manual() -> Self {
+        Self::with_mode(ClockMode::Manual)
+    }
+
+    fn with_mode(mode: ClockMode) -> Self {
+        Self {
+            mode,
+            speed: 1.0,
+            paused: false,
+            pending: 0.0,
+            delta: 0.0,
+            elapsed: 0.0,
+            frame: 0,
+        }
+    }
+
+    /// Pede que o próximo quadro avance `delta` segundos (modo manual)
+    ///
+    /// Chamadas antes do mesmo quadro se acumulam.
+    pub fn step(&mut self, delta: f32) {
+        self.pending += delta.max(0.0);
+    }
+
+    /// Delta do quadro atual em segundos
+    pub fn delta(&self) -> f32 {
+        self.delta
+    }
+
+    /// Tempo total avançado pelo relógio em segundos
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Número de quadros em que o relógio avançou
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Calcula o delta do quadro
+    fn tick(&mut self, real_delta: f32) {
+        let delta = match self.mode {
+            ClockMode::RealTime => real_delta,
+            ClockMode::FixedStep(step) => step,
+            ClockMode::Manual => std::mem::take(&mut self.pending),
+        };
+
+        self.delta = if self.paused { 0.0 } else { delta * self.speed };
+        if self.delta > 0.0 {
+            self.elapsed += self.delta;
+            self.frame += 1;
+        }
+    }
+}
+
+/// Sistema que avança o relógio das animações
+pub fn tick_animation_clock(time: Res<Time>, mut clock: ResMut<AnimationClock>) {
+    clock.tick(time.delta_secs());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animations::{AnimationBuilder, AnimationComponent, AnimationPlugin, Easing};
+
+    #[test]
+    fn manual_clock_advances_only_on_step() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AnimationPlugin))
+            .insert_resource(AnimationClock::manual());
+
+        let timeline = AnimationBuilder::new("move")
+            .move_to(Vec2::new(100.0, 0.0), 1.0, Easing::Linear)
+            .build_and_play();
+        let entity = app
+            .world_mut()
+            .spawn((Transform::default(), AnimationComponent::new(timeline)))
+            .id();
+
+        app.world_mut().resource_mut::<AnimationClock>().step(0.5);
+        app.update();
+        let x = app.world().get::<Transform>(entity).unwrap().translation.x;
+        assert!((x - 50.0).abs() < 1e-3, "x = {x}");
+
+        // Sem `step` o tempo não avança
+        app.update();
+        let x = app.world().get::<Transform>(entity).unwrap().translation.x;
+        assert!((x - 50.0).abs() < 1e-3, "x = {x}");
+    }
+}
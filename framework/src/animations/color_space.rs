@@ -0,0 +1,90 @@
+//! Espaços de cor para interpolação
+//!
+//! Interpolar em RGB linear é rápido, mas passa por tons acinzentados entre
+//! matizes distantes. HSL/HSV e Oklch giram o matiz pelo caminho mais curto e
+//! Oklab/Oklch mantêm a luminosidade percebida uniforme.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use super::easing::lerp;
+
+/// Saturação/croma abaixo da qual o matiz de uma cor é ignorado
+const ACHROMATIC_EPSILON: f32 = 1e-4;
+
+/// Espaço em que as cores de uma track são interpoladas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorSpace {
+    /// RGB linear (padrão)
+    #[default]
+    LinearRgb,
+    /// sRGB (componentes com correção gama)
+    Srgb,
+    /// HSL, com o matiz pelo caminho mais curto
+    Hsl,
+    /// HSV, com o matiz pelo caminho mais curto
+    Hsv,
+    /// Oklab (perceptualmente uniforme)
+    Oklab,
+    /// Oklch, com o matiz pelo caminho mais curto
+    Oklch,
+}
+
+impl ColorSpace {
+    /// Interpola duas cores neste espaço (`t` já com easing aplicado)
+    pub fn mix(&self, a: Color, b: Color, t: f32) -> Color {
+        match self {
+            ColorSpace::LinearRgb => a.to_linear().mix(&b.to_linear(), t).into(),
+            ColorSpace::Srgb => a.to_srgba().mix(&b.to_srgba(), t).into(),
+            ColorSpace::Hsl => {
+                let (a, b) = (Hsla::from(a), Hsla::from(b));
+                let (hue_a, hue_b) = hues(a.hue, a.saturation, b.hue, b.saturation);
+                Hsla::new(
+                    lerp_hue(hue_a, hue_b, t),
+                    lerp(a.saturation, b.saturation, t),
+                    lerp(a.lightness, b.lightness, t),
+                    lerp(a.alpha, b.alpha, t),
+                )
+                .into()
+            }
+            ColorSpace::Hsv => {
+                let (a, b) = (Hsva::from(a), Hsva::from(b));
+                let (hue_a, hue_b) = hues(a.hue, a.saturation, b.hue, b.saturation);
+                Hsva::new(
+                    lerp_hue(hue_a, hue_b, t),
+                    lerp(a.saturation, b.saturation, t),
+                    lerp(a.value, b.value, t),
+                    lerp(a.alpha, b.alpha, t),
+                )
+                .into()
+            }
+            ColorSpace::Oklab => Oklaba::from(a).mix(&Oklaba::from(b), t).into(),
+            ColorSpace::Oklch => {
+                let (a, b) = (Oklcha::from(a), Oklcha::from(b));
+                let (hue_a, hue_b) = hues(a.hue, a.chroma, b.hue, b.chroma);
+                Oklcha::new(
+                    lerp(a.lightness, b.lightness, t),
+                    lerp(a.chroma, b.chroma, t),
+                    lerp_hue(hue_a, hue_b, t),
+                    lerp(a.alpha, b.alpha, t),
+                )
+                .into()
+            }
+        }
+    }
+}
+
+/// Matizes das duas cores; uma cor sem saturação (branco, cinza, preto) usa o
+/// matiz da outra para não girar por cores que não existem nas pontas
+fn hues(hue_a: f32, saturation_a: f32, hue_b: f32, saturation_b: f32) -> (f32, f32) {
+    match (saturation_a < ACHROMATIC_EPSILON, saturation_b < ACHROMATIC_EPSILON) {
+        (true, false) => (hue_b, hue_b),
+        (false, true) => (hue_a, hue_a),
+        _ => (hue_a, hue_b),
+    }
+}
+
+/// Interpola matizes (em graus) pelo caminho mais curto
+fn lerp_hue(a: f32, b: f32, t: f32) -> f32 {
+    let diff = (b - a + 180.0).rem_euclid(360.0) - 180.0;
+    (a + diff * t).rem_euclid(360.0)
+}
@@ -1,8 +1,30 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Número máximo de iterações de Newton-Raphson ao resolver uma curva de Bézier
+const BEZIER_NEWTON_ITERATIONS: usize = 8;
+
+/// Precisão usada ao resolver uma curva de Bézier
+const BEZIER_EPSILON: f32 = 1e-6;
+
+/// Onde acontecem os saltos de `Easing::Steps` (como em `steps()` do CSS)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StepJump {
+    /// Salta no início de cada intervalo
+    Start,
+    /// Salta no fim de cada intervalo
+    #[default]
+    End,
+    /// Sem salto no início nem no fim (começa em 0 e termina em 1)
+    None,
+    /// Salta no início e no fim
+    Both,
+}
 
 /// Tipos de funções de easing para interpolação suave
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub enum Easing {
+    #[default]
     Linear,
     EaseIn,
     EaseOut,
@@ -28,6 +50,12 @@ pub enum Easing {
     EaseInCirc,
     EaseOutCirc,
     EaseInOutCirc,
+    /// Curva de Bézier cúbica como `cubic-bezier(x1, y1, x2, y2)` do CSS
+    CubicBezier(f32, f32, f32, f32),
+    /// Progresso em degraus como `steps(n, jump)` do CSS
+    Steps(u32, StepJump),
+    /// Curva linear por partes definida por pontos (t, valor) ordenados por t
+    Curve(Vec<Vec2>),
 }
 
 impl Easing {
@@ -176,8 +204,112 @@ impl Easing {
                     0.5 * ((1.0 - t * t).sqrt() + 1.0)
                 }
             }
+            
+            // Curvas personalizadas
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(t, *x1, *y1, *x2, *y2),
+            Easing::Steps(steps, jump) => steps_value(t, *steps, *jump),
+            Easing::Curve(points) => curve_value(t, points),
+        }
+    }
+    
+    /// Cria uma curva `cubic-bezier` (x1 e x2 são limitados a 0.0..=1.0)
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Easing::CubicBezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2)
+    }
+    
+    /// Cria uma curva por pontos, ordenando-os por t (pontos não finitos são descartados)
+    pub fn curve(points: impl IntoIterator<Item = Vec2>) -> Self {
+        let mut points: Vec<Vec2> = points.into_iter().filter(|p| p.is_finite()).collect();
+        points.sort_by(|a, b| a.x.total_cmp(&b.x));
+        Easing::Curve(points)
+    }
+}
+
+/// Componente de uma Bézier cúbica com pontos 0, p1, p2, 1 no parâmetro s
+fn bezier_component(s: f32, p1: f32, p2: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+}
+
+/// Derivada de `bezier_component` em relação a s
+fn bezier_derivative(s: f32, p1: f32, p2: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * p1 + 6.0 * inv * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+}
+
+/// Avalia `cubic-bezier(x1, y1, x2, y2)` no tempo t
+///
+/// Resolve x(s) = t por Newton-Raphson e, se não convergir, por bisseção.
+fn cubic_bezier(t: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+    let x1 = x1.clamp(0.0, 1.0);
+    let x2 = x2.clamp(0.0, 1.0);
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    
+    let mut s = t;
+    for _ in 0..BEZIER_NEWTON_ITERATIONS {
+        let error = bezier_component(s, x1, x2) - t;
+        if error.abs() < BEZIER_EPSILON {
+            return bezier_component(s, y1, y2);
         }
+        let slope = bezier_derivative(s, x1, x2);
+        if slope.abs() < BEZIER_EPSILON {
+            break;
+        }
+        s -= error / slope;
+    }
+    
+    // x(s) é monotônica para x1, x2 em [0, 1]
+    let (mut low, mut high) = (0.0_f32, 1.0_f32);
+    s = t;
+    while high - low > BEZIER_EPSILON {
+        if bezier_component(s, x1, x2) < t {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    bezier_component(s, y1, y2)
+}
+
+/// Avalia `steps(n, jump)` no tempo t
+fn steps_value(t: f32, steps: u32, jump: StepJump) -> f32 {
+    let steps = steps.max(1) as f32;
+    let jumps = match jump {
+        StepJump::Start | StepJump::End => steps,
+        StepJump::None => (steps - 1.0).max(1.0),
+        StepJump::Both => steps + 1.0,
+    };
+    
+    let mut step = (t * steps).floor();
+    if matches!(jump, StepJump::Start | StepJump::Both) {
+        step += 1.0;
+    }
+    step.clamp(0.0, jumps) / jumps
+}
+
+/// Avalia uma curva linear por partes no tempo t
+fn curve_value(t: f32, points: &[Vec2]) -> f32 {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return t;
+    };
+    if t <= first.x {
+        return first.y;
+    }
+    if t >= last.x {
+        return last.y;
     }
+    
+    // Primeiro ponto com x > t (existe, pois t < last.x)
+    let index = points.partition_point(|p| p.x <= t);
+    let (a, b) = (points[index - 1], points[index]);
+    let span = b.x - a.x;
+    if span <= 0.0 {
+        return b.y;
+    }
+    a.y + (b.y - a.y) * (t - a.x) / span
 }
 
 /// Interpola entre dois valores usando a função de easing
@@ -185,6 +317,13 @@ pub fn lerp_with_easing<T>(start: T, end: T, t: f32, easing: Easing) -> T
 where
     T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<f32, Output = T> + Copy,
 {
-    let t = easing.apply(t);
+    lerp(start, end, easing.apply(t))
+}
+
+/// Interpolação linear com t já transformado pelo easing
+pub fn lerp<T>(start: T, end: T, t: f32) -> T 
+where
+    T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<f32, Output = T> + Copy,
+{
     start + (end - start) * t
 }
\ No newline at end of file
@@ -0,0 +1,92 @@
+//! Eventos do Bevy emitidos pelas animações
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! fn on_animation_done(
+//!     mut commands: Commands,
+//!     mut completed: EventReader<AnimationCompleted>,
+//! ) {
+//!     for event in completed.read() {
+//!         if event.name == "explode" {
+//!             commands.entity(event.entity).despawn();
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use super::animation_system::AnimationComponent;
+use super::timeline::TimelineEvent;
+
+/// Emitido quando a animação de uma entidade começa
+#[derive(Event, Debug, Clone)]
+pub struct AnimationStarted {
+    /// Entidade animada
+    pub entity: Entity,
+    /// Nome da timeline
+    pub name: String,
+}
+
+/// Emitido quando a animação de uma entidade termina
+#[derive(Event, Debug, Clone)]
+pub struct AnimationCompleted {
+    /// Entidade animada
+    pub entity: Entity,
+    /// Nome da timeline
+    pub name: String,
+}
+
+/// Emitido a cada ciclo completo de uma animação em loop, ping-pong ou repetição
+#[derive(Event, Debug, Clone)]
+pub struct AnimationLooped {
+    /// Entidade animada
+    pub entity: Entity,
+    /// Nome da timeline
+    pub name: String,
+    /// Número de ciclos completos até agora
+    pub count: u32,
+}
+
+/// Emitido quando a reprodução passa por um marcador nomeado da timeline
+#[derive(Event, Debug, Clone)]
+pub struct AnimationMarker {
+    /// Entidade animada
+    pub entity: Entity,
+    /// Nome da timeline
+    pub name: String,
+    /// Nome do marcador
+    pub marker: String,
+}
+
+/// Sistema que converte os eventos das timelines em eventos do Bevy
+pub fn emit_animation_events(
+    mut query: Query<(Entity, &mut AnimationComponent)>,
+    mut started: EventWriter<AnimationStarted>,
+    mut completed: EventWriter<AnimationCompleted>,
+    mut looped: EventWriter<AnimationLooped>,
+    mut markers: EventWriter<AnimationMarker>,
+) {
+    for (entity, mut animation) in query.iter_mut() {
+        if animation.timeline.pending_events.is_empty() {
+            continue;
+        }
+        
+        let name = animation.timeline.name.clone();
+        for event in animation.timeline.drain_events() {
+            match event {
+                TimelineEvent::Started => {
+                    started.write(AnimationStarted { entity, name: name.clone() });
+                }
+                TimelineEvent::Completed => {
+                    completed.write(AnimationCompleted { entity, name: name.clone() });
+                }
+                TimelineEvent::Looped(count) => {
+                    looped.write(AnimationLooped { entity, name: name.clone(), count });
+                }
+                TimelineEvent::Marker(marker) => {
+                    markers.write(AnimationMarker { entity, name: name.clone(), marker });
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use bevy::prelude::*;
-use super::easing::{Easing, lerp_with_easing};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use super::color_space::ColorSpace;
+use super::easing::{Easing, lerp};
+use super::path::PathFollow;
+use super::reflected::FieldValue;
+use crate::geometrics::ShapeMaterial;
 
 /// Propriedade que pode ser animada
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AnimatableProperty {
     /// Posição 2D
     Position(Vec2),
@@ -15,51 +22,189 @@ pub enum AnimatableProperty {
     /// Opacidade (0.0 a 1.0)
     Opacity(f32),
     /// Propriedade customizada com nome e valor
+    ///
+    /// Quando o nome é um caminho `Componente::campo`, o `AnimationPlugin`
+    /// escreve o valor no campo como um `Field` numérico; outros nomes só
+    /// ficam disponíveis nos valores da timeline. Prefira `Field`, que aceita
+    /// vetores e cores.
     Custom(String, f32),
+    /// Campo de um componente qualquer, acessado por reflexão (`Componente::campo`)
+    ///
+    /// O caminho é compartilhado, então interpolar o campo não aloca.
+    Field(Arc<str>, FieldValue),
+    /// Progresso ao longo dos caminhos da track (`n + fração` = fração do
+    /// comprimento do caminho `n`), convertido em posição pela timeline
+    PathProgress(f32),
 }
 
 impl AnimatableProperty {
-    /// Interpola entre duas propriedades do mesmo tipo
-    pub fn interpolate(&self, other: &Self, t: f32, easing: Easing) -> Option<AnimatableProperty> {
+    /// Interpola entre duas propriedades do mesmo tipo (cores em RGB linear)
+    pub fn interpolate(&self, other: &Self, t: f32, easing: &Easing) -> Option<AnimatableProperty> {
+        self.interpolate_in(other, t, easing, ColorSpace::LinearRgb)
+    }
+    
+    /// Interpola entre duas propriedades do mesmo tipo, misturando cores no espaço dado
+    pub fn interpolate_in(
+        &self,
+        other: &Self,
+        t: f32,
+        easing: &Easing,
+        color_space: ColorSpace,
+    ) -> Option<AnimatableProperty> {
+        // Calcular o easing uma única vez para todos os componentes
+        let eased = easing.apply(t);
+        
         match (self, other) {
             (AnimatableProperty::Position(a), AnimatableProperty::Position(b)) => {
-                let x = lerp_with_easing(a.x, b.x, t, easing);
-                let y = lerp_with_easing(a.y, b.y, t, easing);
+                let x = lerp(a.x, b.x, eased);
+                let y = lerp(a.y, b.y, eased);
                 Some(AnimatableProperty::Position(Vec2::new(x, y)))
             }
             (AnimatableProperty::Rotation(a), AnimatableProperty::Rotation(b)) => {
-                Some(AnimatableProperty::Rotation(lerp_with_easing(*a, *b, t, easing)))
+                Some(AnimatableProperty::Rotation(lerp(*a, *b, eased)))
             }
             (AnimatableProperty::Scale(a), AnimatableProperty::Scale(b)) => {
-                let x = lerp_with_easing(a.x, b.x, t, easing);
-                let y = lerp_with_easing(a.y, b.y, t, easing);
+                let x = lerp(a.x, b.x, eased);
+                let y = lerp(a.y, b.y, eased);
                 Some(AnimatableProperty::Scale(Vec2::new(x, y)))
             }
             (AnimatableProperty::Color(a), AnimatableProperty::Color(b)) => {
-                let rgba_a = a.to_linear();
-                let rgba_b = b.to_linear();
-                
-                let r = lerp_with_easing(rgba_a.red, rgba_b.red, t, easing);
-                let g = lerp_with_easing(rgba_a.green, rgba_b.green, t, easing);
-                let b_val = lerp_with_easing(rgba_a.blue, rgba_b.blue, t, easing);
-                let alpha = lerp_with_easing(rgba_a.alpha, rgba_b.alpha, t, easing);
-                
-                Some(AnimatableProperty::Color(Color::linear_rgba(r, g, b_val, alpha)))
+                Some(AnimatableProperty::Color(color_space.mix(*a, *b, eased)))
             }
             (AnimatableProperty::Opacity(a), AnimatableProperty::Opacity(b)) => {
-                Some(AnimatableProperty::Opacity(lerp_with_easing(*a, *b, t, easing)))
+                Some(AnimatableProperty::Opacity(lerp(*a, *b, eased)))
             }
             (AnimatableProperty::Custom(name_a, val_a), AnimatableProperty::Custom(name_b, val_b)) 
                 if name_a == name_b => {
                 Some(AnimatableProperty::Custom(
                     name_a.clone(),
-                    lerp_with_easing(*val_a, *val_b, t, easing)
+                    lerp(*val_a, *val_b, eased)
                 ))
             }
+            (AnimatableProperty::Field(path_a, val_a), AnimatableProperty::Field(path_b, val_b))
+                if path_a == path_b => {
+                val_a
+                    .interpolate_in(val_b, t, easing, color_space)
+                    .map(|value| AnimatableProperty::Field(path_a.clone(), value))
+            }
+            (AnimatableProperty::PathProgress(a), AnimatableProperty::PathProgress(b)) => {
+                Some(AnimatableProperty::PathProgress(lerp(*a, *b, eased)))
+            }
             _ => None, // Tipos incompatíveis
         }
     }
     
+    /// Soma um delta a esta propriedade (para keyframes relativos)
+    pub fn add(&self, delta: &Self) -> Option<AnimatableProperty> {
+        match (self, delta) {
+            (AnimatableProperty::Position(a), AnimatableProperty::Position(b)) => {
+                Some(AnimatableProperty::Position(*a + *b))
+            }
+            (AnimatableProperty::Rotation(a), AnimatableProperty::Rotation(b)) => {
+                Some(AnimatableProperty::Rotation(a + b))
+            }
+            (AnimatableProperty::Scale(a), AnimatableProperty::Scale(b)) => {
+                Some(AnimatableProperty::Scale(*a + *b))
+            }
+            (AnimatableProperty::Color(a), AnimatableProperty::Color(b)) => {
+                let sum = a.to_linear().to_vec4() + b.to_linear().to_vec4();
+                Some(AnimatableProperty::Color(Color::LinearRgba(LinearRgba::from_vec4(sum))))
+            }
+            (AnimatableProperty::Opacity(a), AnimatableProperty::Opacity(b)) => {
+                Some(AnimatableProperty::Opacity(a + b))
+            }
+            (AnimatableProperty::Custom(name_a, val_a), AnimatableProperty::Custom(name_b, val_b))
+                if name_a == name_b => {
+                Some(AnimatableProperty::Custom(name_a.clone(), val_a + val_b))
+            }
+            (AnimatableProperty::Field(path_a, val_a), AnimatableProperty::Field(path_b, val_b))
+                if path_a == path_b => {
+                val_a.add(val_b).map(|value| AnimatableProperty::Field(path_a.clone(), value))
+            }
+            (AnimatableProperty::PathProgress(a), AnimatableProperty::PathProgress(b)) => {
+                Some(AnimatableProperty::PathProgress(a + b))
+            }
+            _ => None,
+        }
+    }
+    
+    /// Nome padrão da track desta propriedade (`"position"`, `"color"`, caminho do campo...)
+    pub fn track_name(&self) -> String {
+        match self {
+            AnimatableProperty::Position(_) => "position".to_string(),
+            AnimatableProperty::Rotation(_) => "rotation".to_string(),
+            AnimatableProperty::Scale(_) => "scale".to_string(),
+            AnimatableProperty::Color(_) => "color".to_string(),
+            AnimatableProperty::Opacity(_) => "opacity".to_string(),
+            AnimatableProperty::Custom(name, _) => name.clone(),
+            AnimatableProperty::Field(path, _) => path.to_string(),
+            AnimatableProperty::PathProgress(_) => "path".to_string(),
+        }
+    }
+    
+    /// Verifica se as duas propriedades pertencem à mesma track padrão
+    ///
+    /// Compara o tipo da propriedade (e o nome, em `Custom` e `Field`) sem alocar.
+    pub(crate) fn same_track(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AnimatableProperty::Custom(a, _), AnimatableProperty::Custom(b, _)) => a == b,
+            (AnimatableProperty::Field(a, _), AnimatableProperty::Field(b, _)) => a == b,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+    
+    /// Componentes numéricos da propriedade (cores em RGB linear)
+    pub(crate) fn to_vec4(&self) -> Vec4 {
+        match self {
+            AnimatableProperty::Position(v) | AnimatableProperty::Scale(v) => v.extend(0.0).extend(0.0),
+            AnimatableProperty::Rotation(v)
+            | AnimatableProperty::Opacity(v)
+            | AnimatableProperty::Custom(_, v)
+            | AnimatableProperty::PathProgress(v) => Vec4::new(*v, 0.0, 0.0, 0.0),
+            AnimatableProperty::Color(c) => c.to_linear().to_vec4(),
+            AnimatableProperty::Field(_, value) => value.to_vec4(),
+        }
+    }
+    
+    /// Propriedade do mesmo tipo com os componentes numéricos `v`
+    pub(crate) fn with_vec4(&self, v: Vec4) -> AnimatableProperty {
+        match self {
+            AnimatableProperty::Position(_) => AnimatableProperty::Position(v.truncate().truncate()),
+            AnimatableProperty::Rotation(_) => AnimatableProperty::Rotation(v.x),
+            AnimatableProperty::Scale(_) => AnimatableProperty::Scale(v.truncate().truncate()),
+            AnimatableProperty::Color(_) => AnimatableProperty::Color(Color::LinearRgba(LinearRgba::from_vec4(v))),
+            AnimatableProperty::Opacity(_) => AnimatableProperty::Opacity(v.x),
+            AnimatableProperty::Custom(name, _) => AnimatableProperty::Custom(name.clone(), v.x),
+            AnimatableProperty::Field(path, value) => AnimatableProperty::Field(path.clone(), value.with_vec4(v)),
+            AnimatableProperty::PathProgress(_) => AnimatableProperty::PathProgress(v.x),
+        }
+    }
+    
+    /// Lê o valor atual desta propriedade a partir do estado da entidade
+    ///
+    /// Retorna `None` para propriedades que não podem ser lidas (ex: `Custom`)
+    /// ou quando a entidade não tem material. Campos (`Field`) são lidos por
+    /// reflexão pelo `AnimationPlugin`.
+    pub fn read_current(&self, transform: &Transform, color: Option<Color>) -> Option<AnimatableProperty> {
+        match self {
+            AnimatableProperty::Position(_) => {
+                Some(AnimatableProperty::Position(transform.translation.truncate()))
+            }
+            AnimatableProperty::Rotation(_) => {
+                let (z, _, _) = transform.rotation.to_euler(EulerRot::ZYX);
+                Some(AnimatableProperty::Rotation(z))
+            }
+            AnimatableProperty::Scale(_) => {
+                Some(AnimatableProperty::Scale(transform.scale.truncate()))
+            }
+            AnimatableProperty::Color(_) => color.map(AnimatableProperty::Color),
+            AnimatableProperty::Opacity(_) => color.map(|c| AnimatableProperty::Opacity(c.alpha())),
+            AnimatableProperty::Custom(_, _)
+            | AnimatableProperty::Field(_, _)
+            | AnimatableProperty::PathProgress(_) => None,
+        }
+    }
+    
     /// Aplica a propriedade a um Transform
     pub fn apply_to_transform(&self, transform: &mut Transform) {
         match self {
@@ -68,7 +213,9 @@ impl AnimatableProperty {
                 transform.translation.y = pos.y;
             }
             AnimatableProperty::Rotation(rot) => {
-                transform.rotation = Quat::from_rotation_z(*rot);
+                // Substituir apenas o ângulo em Z, mantendo as rotações em X e Y
+                let (_, y, x) = transform.rotation.to_euler(EulerRot::ZYX);
+                transform.rotation = Quat::from_euler(EulerRot::ZYX, *rot, y, x);
             }
             AnimatableProperty::Scale(scale) => {
                 transform.scale.x = scale.x;
@@ -90,17 +237,81 @@ impl AnimatableProperty {
             _ => {}
         }
     }
+    
+    /// Aplica a propriedade a um ShapeMaterial (para cor e opacidade)
+    pub fn apply_to_shape_material(&self, material: &mut ShapeMaterial) {
+        match self {
+            AnimatableProperty::Color(color) => {
+                material.color = *color;
+            }
+            AnimatableProperty::Opacity(opacity) => {
+                material.color.set_alpha(*opacity);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Origem do valor de um keyframe
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum KeyframeSource {
+    /// O valor é o da própria propriedade do keyframe
+    #[default]
+    Absolute,
+    /// O valor é lido da entidade quando a animação começa
+    Current,
+    /// O valor é o do keyframe anterior somado a este delta
+    Relative(AnimatableProperty),
+    /// O valor é o mesmo do keyframe anterior
+    Hold,
+}
+
+/// Como uma track de rotação vai de um ângulo ao próximo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RotationMode {
+    /// Interpola os radianos como escritos (permite várias voltas, ex: 0 a 4π)
+    #[default]
+    Absolute,
+    /// Pelo menor ângulo (no máximo meia volta)
+    Shortest,
+    /// Sempre no sentido horário (ângulo decrescente)
+    Clockwise,
+    /// Sempre no sentido anti-horário (ângulo crescente)
+    CounterClockwise,
+}
+
+impl RotationMode {
+    /// Ângulo de destino equivalente a `to` para sair de `from` neste modo
+    pub fn resolve(&self, from: f32, to: f32) -> f32 {
+        use std::f32::consts::{PI, TAU};
+        match self {
+            RotationMode::Absolute => to,
+            RotationMode::Shortest => from + ((to - from + PI).rem_euclid(TAU) - PI),
+            RotationMode::Clockwise => from - (from - to).rem_euclid(TAU),
+            RotationMode::CounterClockwise => from + (to - from).rem_euclid(TAU),
+        }
+    }
 }
 
 /// Um keyframe representa um ponto específico na timeline
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Keyframe {
     /// Tempo em segundos desde o início da animação
     pub time: f32,
-    /// Propriedade a ser animada
+    /// Propriedade a ser animada (valor resolvido para keyframes não absolutos)
     pub property: AnimatableProperty,
     /// Função de easing para transição até este keyframe
+    #[serde(default)]
     pub easing: Easing,
+    /// Origem do valor do keyframe
+    #[serde(default)]
+    pub source: KeyframeSource,
+    /// Espaço de cor da transição até este keyframe (`None` = o da track)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color_space: Option<ColorSpace>,
+    /// Modo de rotação da transição até este keyframe (`None` = o da track)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation_mode: Option<RotationMode>,
 }
 
 impl Keyframe {
@@ -110,6 +321,36 @@ impl Keyframe {
             time,
             property,
             easing,
+            source: KeyframeSource::Absolute,
+            color_space: None,
+            rotation_mode: None,
+        }
+    }
+    
+    /// Cria um keyframe cujo valor é lido da entidade quando a animação começa
+    ///
+    /// `fallback` define o tipo da propriedade e o valor usado se a entidade
+    /// não puder fornecê-lo.
+    pub fn from_current(time: f32, fallback: AnimatableProperty) -> Self {
+        Self {
+            source: KeyframeSource::Current,
+            ..Self::new(time, fallback, Easing::Linear)
+        }
+    }
+    
+    /// Cria um keyframe relativo ao valor do keyframe anterior
+    pub fn relative(time: f32, delta: AnimatableProperty, easing: Easing) -> Self {
+        Self {
+            source: KeyframeSource::Relative(delta.clone()),
+            ..Self::new(time, delta, easing)
+        }
+    }
+    
+    /// Cria um keyframe que mantém o valor do keyframe anterior
+    pub fn hold(time: f32, property: AnimatableProperty) -> Self {
+        Self {
+            source: KeyframeSource::Hold,
+            ..Self::new(time, property, Easing::Linear)
         }
     }
     
@@ -123,6 +364,14 @@ impl Keyframe {
         Self::new(time, AnimatableProperty::Rotation(rotation), easing)
     }
     
+    /// Cria um keyframe de rotação com um modo de rotação
+    pub fn rotation_with(time: f32, rotation: f32, mode: RotationMode, easing: Easing) -> Self {
+        Self {
+            rotation_mode: Some(mode),
+            ..Self::rotation(time, rotation, easing)
+        }
+    }
+    
     /// Cria um keyframe de escala
     pub fn scale(time: f32, scale: Vec2, easing: Easing) -> Self {
         Self::new(time, AnimatableProperty::Scale(scale), easing)
@@ -133,19 +382,81 @@ impl Keyframe {
         Self::new(time, AnimatableProperty::Color(color), easing)
     }
     
+    /// Cria um keyframe de cor interpolado no espaço de cor dado
+    pub fn color_in(time: f32, color: Color, color_space: ColorSpace, easing: Easing) -> Self {
+        Self {
+            color_space: Some(color_space),
+            ..Self::color(time, color, easing)
+        }
+    }
+    
     /// Cria um keyframe de opacidade
     pub fn opacity(time: f32, opacity: f32, easing: Easing) -> Self {
         Self::new(time, AnimatableProperty::Opacity(opacity), easing)
     }
+    
+    /// Cria um keyframe do início do trecho visível de um contorno (`StrokeTrim::start`)
+    pub fn trim_start(time: f32, start: f32, easing: Easing) -> Self {
+        Self::field(time, "StrokeTrim::start", start, easing)
+    }
+    
+    /// Cria um keyframe do fim do trecho visível de um contorno (`StrokeTrim::end`)
+    pub fn trim_end(time: f32, end: f32, easing: Easing) -> Self {
+        Self::field(time, "StrokeTrim::end", end, easing)
+    }
+    
+    /// Cria um keyframe do deslocamento do trecho visível, em voltas (`StrokeTrim::offset`)
+    pub fn trim_offset(time: f32, offset: f32, easing: Easing) -> Self {
+        Self::field(time, "StrokeTrim::offset", offset, easing)
+    }
+    
+    /// Helper para criar keyframe de um campo de componente (`Componente::campo`)
+    pub fn field(time: f32, path: impl Into<Arc<str>>, value: impl Into<FieldValue>, easing: Easing) -> Self {
+        Self::new(time, AnimatableProperty::Field(path.into(), value.into()), easing)
+    }
 }
 
 /// Grupo de keyframes que afetam a mesma propriedade
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyframeTrack {
     /// Nome da track (ex: "position", "rotation", etc)
     pub name: String,
     /// Lista de keyframes ordenados por tempo
     pub keyframes: Vec<Keyframe>,
+    /// Caminhos seguidos por keyframes `PathProgress`, na ordem em que são percorridos
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<PathFollow>,
+    /// Espaço de cor padrão das transições da track
+    #[serde(default, skip_serializing_if = "is_linear_rgb")]
+    pub color_space: ColorSpace,
+    /// Modo de rotação padrão das transições da track
+    #[serde(default, skip_serializing_if = "is_absolute")]
+    pub rotation_mode: RotationMode,
+    /// Último trecho encontrado por `get_value_at`
+    #[serde(skip)]
+    segment: SegmentCache,
+}
+
+/// Índice do último trecho interpolado de uma track
+///
+/// Durante a reprodução o tempo avança pouco a cada frame, então o trecho
+/// seguinte costuma ser o mesmo ou o próximo; a busca binária só é usada
+/// quando o tempo salta.
+#[derive(Debug, Default)]
+struct SegmentCache(AtomicUsize);
+
+impl Clone for SegmentCache {
+    fn clone(&self) -> Self {
+        Self(AtomicUsize::new(self.0.load(Ordering::Relaxed)))
+    }
+}
+
+fn is_linear_rgb(color_space: &ColorSpace) -> bool {
+    *color_space == ColorSpace::LinearRgb
+}
+
+fn is_absolute(rotation_mode: &RotationMode) -> bool {
+    *rotation_mode == RotationMode::Absolute
 }
 
 impl KeyframeTrack {
@@ -153,14 +464,27 @@ impl KeyframeTrack {
         Self {
             name,
             keyframes: Vec::new(),
+            paths: Vec::new(),
+            color_space: ColorSpace::LinearRgb,
+            rotation_mode: RotationMode::Absolute,
+            segment: SegmentCache::default(),
         }
     }
     
     /// Adiciona um keyframe à track
+    ///
+    /// O keyframe é inserido na posição ordenada por tempo, depois dos
+    /// keyframes que já existem no mesmo tempo.
     pub fn add_keyframe(&mut self, keyframe: Keyframe) {
-        self.keyframes.push(keyframe);
-        // Manter ordenado por tempo
-        self.keyframes.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap());
+        let index = self.keyframes.partition_point(|k| k.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+    }
+    
+    /// Adiciona vários keyframes, ordenando a track uma única vez
+    pub fn add_keyframes(&mut self, keyframes: impl IntoIterator<Item = Keyframe>) {
+        self.keyframes.extend(keyframes);
+        // Ordenação estável: keyframes no mesmo tempo mantêm a ordem de inserção
+        self.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
     }
     
     /// Obtém o valor interpolado no tempo especificado
@@ -179,19 +503,108 @@ impl KeyframeTrack {
             return Some(self.keyframes.last().unwrap().property.clone());
         }
         
+        // NaN não cai em nenhum trecho (e pode chegar aqui com um único keyframe)
+        if time.is_nan() {
+            return None;
+        }
+        
         // Encontrar os dois keyframes entre os quais interpolar
-        for i in 0..self.keyframes.len() - 1 {
-            let current = &self.keyframes[i];
-            let next = &self.keyframes[i + 1];
-            
-            if time >= current.time && time <= next.time {
-                // Calcular o t normalizado entre os dois keyframes
-                let t = (time - current.time) / (next.time - current.time);
-                return current.property.interpolate(&next.property, t, next.easing);
-            }
+        let i = self.segment_at(time);
+        let current = &self.keyframes[i];
+        let next = &self.keyframes[i + 1];
+        
+        // Calcular o t normalizado entre os dois keyframes
+        let t = (time - current.time) / (next.time - current.time);
+        let color_space = next.color_space.unwrap_or(self.color_space);
+        
+        // Ajustar o ângulo de destino ao modo de rotação
+        if let (AnimatableProperty::Rotation(from), AnimatableProperty::Rotation(to)) =
+            (&current.property, &next.property)
+        {
+            let mode = next.rotation_mode.unwrap_or(self.rotation_mode);
+            let to = AnimatableProperty::Rotation(mode.resolve(*from, *to));
+            return current.property.interpolate_in(&to, t, &next.easing, color_space);
         }
         
-        None
+        current.property.interpolate_in(&next.property, t, &next.easing, color_space)
+    }
+    
+    /// Índice `i` do trecho com `keyframes[i].time < time <= keyframes[i + 1].time`
+    ///
+    /// Requer `time` estritamente entre o primeiro e o último keyframe.
+    fn segment_at(&self, time: f32) -> usize {
+        let contains = |i: usize| {
+            i + 1 < self.keyframes.len()
+                && self.keyframes[i].time < time
+                && time <= self.keyframes[i + 1].time
+        };
+        
+        // Tentar o trecho anterior e o seguinte antes da busca binária
+        let cached = self.segment.0.load(Ordering::Relaxed);
+        let index = if contains(cached) {
+            cached
+        } else if contains(cached + 1) {
+            cached + 1
+        } else {
+            self.keyframes.partition_point(|k| k.time < time).saturating_sub(1)
+        };
+        self.segment.0.store(index, Ordering::Relaxed);
+        index
+    }
+    
+    /// Posição (e rotação, se o caminho girar a entidade) para um `PathProgress`
+    ///
+    /// O progresso `n + f` com `0 < f <= 1` corresponde à fração `f` do caminho
+    /// `n`, então o fim de um caminho é mantido até o próximo começar.
+    pub fn path_pose(&self, progress: f32) -> Option<(Vec2, Option<f32>)> {
+        if self.paths.is_empty() {
+            return None;
+        }
+        let index = (progress.ceil() as isize - 1).clamp(0, self.paths.len() as isize - 1) as usize;
+        let fraction = (progress - index as f32).clamp(0.0, 1.0);
+        Some(self.paths[index].pose(fraction))
+    }
+    
+    /// Verifica se a track produz valores da mesma propriedade que `property`
+    pub(crate) fn produces(&self, property: &AnimatableProperty) -> bool {
+        match self.keyframes.first().map(|keyframe| &keyframe.property) {
+            Some(AnimatableProperty::PathProgress(_)) => match property {
+                AnimatableProperty::Position(_) => true,
+                AnimatableProperty::Rotation(_) => self.paths.iter().any(|path| path.auto_rotate),
+                _ => false,
+            },
+            Some(first) => first.same_track(property),
+            None => false,
+        }
+    }
+    
+    /// Resolve os valores de keyframes não absolutos
+    ///
+    /// `current` é o valor atual da propriedade na entidade, se disponível.
+    pub fn resolve(&mut self, current: Option<&AnimatableProperty>) {
+        let mut previous: Option<AnimatableProperty> = current.cloned();
+        
+        for keyframe in self.keyframes.iter_mut() {
+            match &keyframe.source {
+                KeyframeSource::Absolute => {}
+                KeyframeSource::Current => {
+                    if let Some(current) = current {
+                        keyframe.property = current.clone();
+                    }
+                }
+                KeyframeSource::Relative(delta) => {
+                    if let Some(value) = previous.as_ref().and_then(|p| p.add(delta)) {
+                        keyframe.property = value;
+                    }
+                }
+                KeyframeSource::Hold => {
+                    if let Some(previous) = &previous {
+                        keyframe.property = previous.clone();
+                    }
+                }
+            }
+            previous = Some(keyframe.property.clone());
+        }
     }
     
     /// Obtém a duração total da track
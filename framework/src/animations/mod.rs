@@ -2,6 +2,11 @@
 //! 
 //! Fornece um sistema completo de animação baseado em timeline para geometrias 2D no Bevy.
 //! 
+//! As animações começam do estado atual da entidade: os valores iniciais de
+//! posição, rotação, escala e cor são lidos do `Transform` e do material quando
+//! a animação é reproduzida pela primeira vez, e `move_by`/`rotate_by` são
+//! relativos a esses valores.
+//! 
 //! # Exemplo de uso:
 //! ```rust
 //! use animations::{AnimationTimeline, AnimationBuilder, Easing};
@@ -13,6 +18,35 @@
 //!     .scale_to(Vec2::splat(2.0), 1.5, Easing::EaseOut)
 //!     .color_to(Color::srgb(1.0, 0.0, 0.0), 1.0, Easing::EaseIn)
 //!     .build();
+//! 
+//! // Passos em paralelo: mover enquanto gira
+//! let animation = AnimationBuilder::new("move_and_spin")
+//!     .parallel(|b| b
+//!         .move_to(Vec2::new(100.0, 0.0), 1.0, Easing::EaseInOut)
+//!         .rotate_to(std::f32::consts::PI, 1.0, Easing::Linear))
+//!     .build();
+//! 
+//! // Campos de componentes próprios via reflexão
+//! // (o componente precisa de #[derive(Reflect)] e #[reflect(Component)])
+//! let animation = AnimationBuilder::new("gauge")
+//!     .animate_field("MyGauge::value", 75.0, 1.0, Easing::EaseOut)
+//!     .build();
+//! 
+//! // Movimento ao longo de um caminho, girando para a tangente
+//! let animation = AnimationBuilder::new("orbit")
+//!     .follow_path_rotated(MotionPath::from_points(points, true), 0.0, 4.0, Easing::Linear)
+//!     .repeat()
+//!     .build();
+//! 
+//! // Várias animações ao mesmo tempo: pulso contínuo e tremor aditivo
+//! let stack = AnimationStack::new()
+//!     .with_layer(AnimationLayer::new("idle", AnimationPresets::pulse(1.1, 1.0)))
+//!     .with_layer(AnimationLayer::additive("hit", AnimationPresets::shake(10.0, 0.4)));
+//! 
+//! // Mola física: sem duração fixa, pode mudar de alvo no meio do movimento
+//! commands.entity(entity).insert(
+//!     SpringAnimation::new().to(AnimatableProperty::Position(Vec2::ZERO), SpringConfig::wobbly()),
+//! );
 //! ```
 
 mod timeline;
@@ -20,13 +54,41 @@ mod keyframe;
 mod animation_system;
 mod builder;
 mod easing;
+mod reflected;
+mod events;
+mod orchestrator;
+mod asset;
+mod spring;
+mod path;
+mod stack;
+mod state_machine;
+mod color_space;
+mod procedural;
+mod clock;
 
 // Re-exportar tipos públicos
-pub use timeline::{AnimationTimeline, AnimationState, AnimationMode};
-pub use keyframe::{Keyframe, AnimatableProperty};
+pub use timeline::{AnimationTimeline, AnimationState, AnimationMode, TimelineEvent};
+pub use keyframe::{Keyframe, KeyframeSource, AnimatableProperty, RotationMode};
 pub use animation_system::{AnimationPlugin, AnimationComponent};
 pub use builder::{AnimationBuilder, AnimationPresets};
-pub use easing::Easing;
+pub use easing::{Easing, StepJump, lerp_with_easing};
+pub use reflected::FieldValue;
+pub use events::{AnimationStarted, AnimationCompleted, AnimationLooped, AnimationMarker};
+pub use asset::{
+    TimelineDefinition, AnimationAsset, AnimationAssetLoader, AnimationAssetError, AnimationHandle,
+};
+pub use color_space::ColorSpace;
+pub use procedural::{ProceduralTrack, Waveform};
+pub use clock::{AnimationClock, ClockMode};
+pub use state_machine::{
+    AnimationStateMachine, TransitionCondition, StateTransition, AnimationStateChanged, STATE_LAYER,
+};
+pub use stack::{AnimationStack, AnimationLayer, LayerBlend};
+pub use path::{MotionPath, PathFollow};
+pub use spring::{SpringAnimation, SpringConfig, SpringSettled};
+pub use orchestrator::{
+    AnimationOrchestrator, Orchestration, Stagger, AnimationGroupId, AnimationGroupCompleted,
+};
 
 // Re-exportar um prelude para facilitar imports
 pub mod prelude {
@@ -37,6 +99,34 @@ pub mod prelude {
         AnimationBuilder,
         AnimationPresets,
         Easing,
+        StepJump,
         AnimatableProperty,
+        FieldValue,
+        AnimationStarted,
+        AnimationCompleted,
+        AnimationLooped,
+        AnimationMarker,
+        AnimationOrchestrator,
+        Orchestration,
+        Stagger,
+        AnimationGroupCompleted,
+        AnimationHandle,
+        AnimationAsset,
+        TimelineDefinition,
+        SpringAnimation,
+        SpringConfig,
+        SpringSettled,
+        MotionPath,
+        AnimationStack,
+        AnimationLayer,
+        LayerBlend,
+        AnimationStateMachine,
+        TransitionCondition,
+        AnimationStateChanged,
+        ColorSpace,
+        RotationMode,
+        ProceduralTrack,
+        Waveform,
+        AnimationClock,
     };
 }
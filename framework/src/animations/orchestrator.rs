@@ -0,0 +1,279 @@
+//! Orquestração de animações entre várias entidades
+//!
+//! Uma `Orchestration` é uma sequência de etapas; cada etapa inicia animações
+//! em várias entidades ao mesmo tempo (opcionalmente com atrasos escalonados)
+//! e a próxima etapa começa quando todas as animações da anterior terminam.
+//! Quando a última etapa termina, o evento `AnimationGroupCompleted` é emitido.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! fn intro(
+//!     mut orchestrator: ResMut<AnimationOrchestrator>,
+//!     shapes: Query<Entity, With<Shape>>,
+//!     title: Single<Entity, With<Title>>,
+//! ) {
+//!     let entities: Vec<Entity> = shapes.iter().collect();
+//!     orchestrator.run(
+//!         Orchestration::new("intro")
+//!             .stagger(&entities, Stagger::FromCenter(0.05), |_, _| AnimationPresets::fade_in(0.4))
+//!             .then(*title, AnimationPresets::fade_in(0.6)),
+//!     );
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use super::animation_system::AnimationComponent;
+use super::events::AnimationCompleted;
+use super::timeline::{AnimationMode, AnimationTimeline};
+
+/// Como distribuir os atrasos entre as entidades de uma etapa
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stagger {
+    /// Cada entidade começa `intervalo` segundos depois da anterior
+    Linear(f32),
+    /// As entidades do meio começam primeiro e o atraso cresce em direção às pontas
+    FromCenter(f32),
+    /// Atraso aleatório entre 0 e o valor máximo
+    Random(f32),
+    /// Entidades dispostas em grade (linha a linha); o atraso cresce com a
+    /// distância até a célula `from` (coluna, linha)
+    Grid {
+        /// Número de colunas da grade
+        columns: usize,
+        /// Atraso por célula de distância
+        interval: f32,
+        /// Célula de onde a onda parte
+        from: Vec2,
+    },
+}
+
+impl Stagger {
+    /// Calcula o atraso de cada uma das `count` entidades
+    pub fn offsets(&self, count: usize) -> Vec<f32> {
+        match *self {
+            Stagger::Linear(interval) => (0..count).map(|i| i as f32 * interval).collect(),
+            Stagger::FromCenter(interval) => {
+                let center = count.saturating_sub(1) as f32 / 2.0;
+                // Arredondar para que os dois elementos centrais de uma lista par comecem juntos
+                (0..count)
+                    .map(|i| (i as f32 - center).abs().floor() * interval)
+                    .collect()
+            }
+            Stagger::Random(max) => (0..count).map(|_| rand::random::<f32>() * max).collect(),
+            Stagger::Grid { columns, interval, from } => {
+                let columns = columns.max(1);
+                (0..count)
+                    .map(|i| {
+                        let cell = Vec2::new((i % columns) as f32, (i / columns) as f32);
+                        cell.distance(from) * interval
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Identificador de um grupo de animações em execução
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnimationGroupId(u64);
+
+/// Emitido quando todas as etapas de uma orquestração terminam
+#[derive(Event, Debug, Clone)]
+pub struct AnimationGroupCompleted {
+    /// Grupo que terminou
+    pub group: AnimationGroupId,
+    /// Nome da orquestração
+    pub name: String,
+}
+
+/// Etapa de uma orquestração: animações iniciadas juntas
+type Stage = Vec<(Entity, AnimationTimeline)>;
+
+/// Descrição de uma sequência de animações em várias entidades
+pub struct Orchestration {
+    name: String,
+    stages: Vec<Stage>,
+}
+
+impl Orchestration {
+    /// Cria uma orquestração vazia
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            stages: Vec::new(),
+        }
+    }
+
+    /// Adiciona uma etapa que anima várias entidades com atrasos escalonados
+    ///
+    /// `make` recebe o índice e a entidade e cria a timeline de cada uma; o
+    /// atraso do escalonamento é somado ao delay da timeline.
+    pub fn stagger(
+        mut self,
+        entities: &[Entity],
+        stagger: Stagger,
+        mut make: impl FnMut(usize, Entity) -> AnimationTimeline,
+    ) -> Self {
+        let offsets = stagger.offsets(entities.len());
+        let stage = entities
+            .iter()
+            .zip(offsets)
+            .enumerate()
+            .map(|(index, (&entity, offset))| {
+                let mut timeline = make(index, entity);
+                timeline.set_delay(timeline.delay + offset);
+                (entity, timeline)
+            })
+            .collect();
+        self.stages.push(stage);
+        self
+    }
+
+    /// Adiciona uma etapa com uma única animação, iniciada quando a etapa
+    /// anterior termina
+    pub fn then(mut self, entity: Entity, timeline: AnimationTimeline) -> Self {
+        self.stages.push(vec![(entity, timeline)]);
+        self
+    }
+
+    /// Adiciona uma animação à última etapa (executada junto com as outras dela)
+    pub fn with(mut self, entity: Entity, timeline: AnimationTimeline) -> Self {
+        match self.stages.last_mut() {
+            Some(stage) => stage.push((entity, timeline)),
+            None => self.stages.push(vec![(entity, timeline)]),
+        }
+        self
+    }
+}
+
+/// Grupo em execução
+struct ActiveGroup {
+    id: AnimationGroupId,
+    name: String,
+    pending: VecDeque<Stage>,
+    /// Animações da etapa atual ainda em execução (entidade, nome da timeline)
+    running: Vec<(Entity, String)>,
+}
+
+/// Recurso que executa orquestrações de animações
+///
+/// Animações em loop infinito ou ping-pong nunca terminam e por isso
+/// bloqueiam a etapa.
+#[derive(Resource, Default)]
+pub struct AnimationOrchestrator {
+    next_id: u64,
+    groups: Vec<ActiveGroup>,
+}
+
+impl AnimationOrchestrator {
+    /// Inicia uma orquestração e retorna o identificador do grupo
+    pub fn run(&mut self, orchestration: Orchestration) -> AnimationGroupId {
+        let id = AnimationGroupId(self.next_id);
+        self.next_id += 1;
+
+        for stage in &orchestration.stages {
+            for (_, timeline) in stage {
+                if matches!(timeline.mode, AnimationMode::Loop | AnimationMode::PingPong) {
+                    warn!(
+                        "A animação '{}' da orquestração '{}' está em loop e nunca termina",
+                        timeline.name, orchestration.name
+                    );
+                }
+            }
+        }
+
+        self.groups.push(ActiveGroup {
+            id,
+            name: orchestration.name,
+            pending: orchestration.stages.into(),
+            running: Vec::new(),
+        });
+        id
+    }
+
+    /// Anima várias entidades com atrasos escalonados
+    pub fn stagger(
+        &mut self,
+        name: impl Into<String>,
+        entities: &[Entity],
+        stagger: Stagger,
+        make: impl FnMut(usize, Entity) -> AnimationTimeline,
+    ) -> AnimationGroupId {
+        self.run(Orchestration::new(name).stagger(entities, stagger, make))
+    }
+
+    /// Executa as animações uma após a outra, cada uma na sua entidade
+    pub fn chain(
+        &mut self,
+        name: impl Into<String>,
+        steps: impl IntoIterator<Item = (Entity, AnimationTimeline)>,
+    ) -> AnimationGroupId {
+        let orchestration = steps
+            .into_iter()
+            .fold(Orchestration::new(name), |o, (entity, timeline)| o.then(entity, timeline));
+        self.run(orchestration)
+    }
+
+    /// Verifica se o grupo ainda está em execução
+    pub fn is_running(&self, group: AnimationGroupId) -> bool {
+        self.groups.iter().any(|g| g.id == group)
+    }
+
+    /// Cancela as etapas que ainda não começaram (as animações em execução continuam)
+    ///
+    /// O evento de conclusão do grupo não é emitido.
+    pub fn cancel(&mut self, group: AnimationGroupId) {
+        self.groups.retain(|g| g.id != group);
+    }
+}
+
+/// Sistema que inicia as etapas das orquestrações e detecta o fim de cada uma
+pub fn update_orchestrations(
+    mut commands: Commands,
+    mut orchestrator: ResMut<AnimationOrchestrator>,
+    mut completed: EventReader<AnimationCompleted>,
+    animations: Query<&AnimationComponent>,
+    mut group_completed: EventWriter<AnimationGroupCompleted>,
+) {
+    let completed: Vec<&AnimationCompleted> = completed.read().collect();
+
+    orchestrator.groups.retain_mut(|group| {
+        // Remover animações concluídas, substituídas ou de entidades removidas
+        group.running.retain(|(entity, name)| {
+            let finished = completed.iter().any(|e| e.entity == *entity && e.name == *name);
+            let still_running = animations
+                .get(*entity)
+                .is_ok_and(|animation| animation.timeline.name == *name);
+            !finished && still_running
+        });
+
+        if !group.running.is_empty() {
+            return true;
+        }
+
+        // Iniciar a próxima etapa não vazia
+        while let Some(stage) = group.pending.pop_front() {
+            if stage.is_empty() {
+                continue;
+            }
+            for (entity, mut timeline) in stage {
+                let Ok(mut entity_commands) = commands.get_entity(entity) else {
+                    continue;
+                };
+                timeline.play();
+                group.running.push((entity, timeline.name.clone()));
+                entity_commands.insert(AnimationComponent::new(timeline));
+            }
+            if !group.running.is_empty() {
+                return true;
+            }
+        }
+
+        group_completed.write(AnimationGroupCompleted {
+            group: group.id,
+            name: group.name.clone(),
+        });
+        false
+    });
+}
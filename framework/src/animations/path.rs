@@ -0,0 +1,276 @@
+//! Movimento ao longo de caminhos
+//!
+//! Um `MotionPath` é uma polilinha (contorno) ou uma sequência de curvas de
+//! Bézier amostradas. A posição é parametrizada pelo comprimento do arco, então
+//! um progresso linear move a entidade com velocidade constante; o easing do
+//! passo é aplicado sobre o comprimento do caminho.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! let path = MotionPath::new(Vec2::ZERO)
+//!     .cubic_to(Vec2::new(50.0, 120.0), Vec2::new(150.0, -120.0), Vec2::new(200.0, 0.0))
+//!     .line_to(Vec2::new(300.0, 0.0));
+//!
+//! let animation = AnimationBuilder::new("voo")
+//!     .follow_path_rotated(path, 0.0, 2.0, Easing::EaseInOut)
+//!     .build();
+//!
+//! // Contornos também podem ser usados como caminho
+//! let orbit = MotionPath::from_points(contour::resample(&points, 64, true), true);
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::geometrics::contour;
+
+/// Número de segmentos usados para amostrar cada curva de Bézier
+const PATH_CURVE_SEGMENTS: usize = 32;
+
+/// Caminho 2D parametrizado pelo comprimento do arco
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "MotionPathData", into = "MotionPathData")]
+pub struct MotionPath {
+    points: Vec<Vec2>,
+    closed: bool,
+    /// Comprimento acumulado até cada vértice (inclui o retorno ao início se fechado)
+    distances: Vec<f32>,
+}
+
+/// Forma serializada de um `MotionPath` (sem a tabela de comprimentos)
+#[derive(Serialize, Deserialize)]
+struct MotionPathData {
+    points: Vec<Vec2>,
+    #[serde(default)]
+    closed: bool,
+}
+
+impl From<MotionPathData> for MotionPath {
+    fn from(data: MotionPathData) -> Self {
+        MotionPath::from_points(data.points, data.closed)
+    }
+}
+
+impl From<MotionPath> for MotionPathData {
+    fn from(path: MotionPath) -> Self {
+        MotionPathData {
+            points: path.points,
+            closed: path.closed,
+        }
+    }
+}
+
+impl MotionPath {
+    /// Cria um caminho que começa em `start`
+    pub fn new(start: Vec2) -> Self {
+        Self::from_points(vec![start], false)
+    }
+
+    /// Cria um caminho a partir de um contorno (pontos duplicados são removidos)
+    pub fn from_points(points: impl Into<Vec<Vec2>>, closed: bool) -> Self {
+        let mut path = Self {
+            points: contour::remove_duplicates(&points.into(), 0.0, closed),
+            closed,
+            distances: Vec::new(),
+        };
+        path.update_distances();
+        path
+    }
+
+    /// Segmento de reta entre dois pontos
+    pub fn line(from: Vec2, to: Vec2) -> Self {
+        Self::new(from).line_to(to)
+    }
+
+    /// Curva de Bézier quadrática
+    pub fn quadratic_bezier(p0: Vec2, p1: Vec2, p2: Vec2) -> Self {
+        Self::new(p0).quadratic_to(p1, p2)
+    }
+
+    /// Curva de Bézier cúbica
+    pub fn cubic_bezier(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2) -> Self {
+        Self::new(p0).cubic_to(p1, p2, p3)
+    }
+
+    /// Adiciona um segmento de reta até `point`
+    pub fn line_to(self, point: Vec2) -> Self {
+        self.extend([point])
+    }
+
+    /// Adiciona uma curva de Bézier quadrática até `point`
+    pub fn quadratic_to(self, control: Vec2, point: Vec2) -> Self {
+        let p0 = self.end();
+        self.extend((1..=PATH_CURVE_SEGMENTS).map(|i| {
+            let t = i as f32 / PATH_CURVE_SEGMENTS as f32;
+            let mt = 1.0 - t;
+            p0 * mt * mt + control * 2.0 * mt * t + point * t * t
+        }))
+    }
+
+    /// Adiciona uma curva de Bézier cúbica até `point`
+    pub fn cubic_to(self, control1: Vec2, control2: Vec2, point: Vec2) -> Self {
+        let p0 = self.end();
+        self.extend((1..=PATH_CURVE_SEGMENTS).map(|i| {
+            let t = i as f32 / PATH_CURVE_SEGMENTS as f32;
+            let mt = 1.0 - t;
+            p0 * mt * mt * mt
+                + control1 * 3.0 * mt * mt * t
+                + control2 * 3.0 * mt * t * t
+                + point * t * t * t
+        }))
+    }
+
+    /// Fecha o caminho (o último ponto se conecta ao primeiro)
+    pub fn close(self) -> Self {
+        Self::from_points(self.points, true)
+    }
+
+    /// Desloca todos os pontos do caminho
+    pub fn translated(mut self, offset: Vec2) -> Self {
+        for point in self.points.iter_mut() {
+            *point += offset;
+        }
+        self
+    }
+
+    /// Pontos do caminho
+    pub fn points(&self) -> &[Vec2] {
+        &self.points
+    }
+
+    /// Verifica se o caminho é fechado
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Comprimento total do caminho
+    pub fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or(0.0)
+    }
+
+    /// Posição e tangente (normalizada) a uma distância do início do caminho
+    pub fn sample(&self, distance: f32) -> (Vec2, Vec2) {
+        match self.points.len() {
+            0 => return (Vec2::ZERO, Vec2::X),
+            1 => return (self.points[0], Vec2::X),
+            _ => {}
+        }
+
+        let distance = distance.clamp(0.0, self.length());
+        // Segmento que contém a distância: do vértice `index - 1` ao `index`
+        let index = self
+            .distances
+            .partition_point(|&d| d <= distance)
+            .clamp(1, self.distances.len() - 1);
+        let start = self.vertex(index - 1);
+        let end = self.vertex(index);
+        let segment = self.distances[index] - self.distances[index - 1];
+        let t = if segment > 0.0 {
+            (distance - self.distances[index - 1]) / segment
+        } else {
+            0.0
+        };
+
+        (start.lerp(end, t), (end - start).normalize_or(Vec2::X))
+    }
+
+    /// Posição e tangente em uma fração do comprimento (0.0 a 1.0)
+    pub fn sample_progress(&self, progress: f32) -> (Vec2, Vec2) {
+        self.sample(progress * self.length())
+    }
+
+    /// Último ponto do caminho
+    fn end(&self) -> Vec2 {
+        self.points.last().copied().unwrap_or(Vec2::ZERO)
+    }
+
+    /// Vértice `index`, voltando ao primeiro no segmento de fechamento
+    fn vertex(&self, index: usize) -> Vec2 {
+        self.points[index % self.points.len()]
+    }
+
+    /// Acrescenta pontos ao fim, estendendo a tabela de comprimentos sem recalculá-la
+    fn extend(mut self, points: impl IntoIterator<Item = Vec2>) -> Self {
+        // O segmento de fechamento muda com o último ponto; recalcular tudo
+        if self.closed {
+            self.points.extend(points);
+            return Self::from_points(self.points, true);
+        }
+
+        for point in points {
+            match self.points.last() {
+                // Pontos duplicados são ignorados, como em `from_points`
+                Some(last) if last.distance(point) <= 0.0 => {}
+                Some(&last) => {
+                    self.distances.push(self.length() + last.distance(point));
+                    self.points.push(point);
+                }
+                None => {
+                    self.distances.push(0.0);
+                    self.points.push(point);
+                }
+            }
+        }
+        self
+    }
+
+    fn update_distances(&mut self) {
+        let segments = if self.closed && self.points.len() > 1 {
+            self.points.len()
+        } else {
+            self.points.len().saturating_sub(1)
+        };
+
+        self.distances.clear();
+        if self.points.is_empty() {
+            return;
+        }
+        self.distances.push(0.0);
+        let mut total = 0.0;
+        for i in 0..segments {
+            total += self.vertex(i).distance(self.vertex(i + 1));
+            self.distances.push(total);
+        }
+    }
+}
+
+/// Caminho seguido por uma track de movimento
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathFollow {
+    /// Caminho em coordenadas do mundo
+    pub path: MotionPath,
+    /// Se a rotação acompanha a tangente do caminho
+    #[serde(default)]
+    pub auto_rotate: bool,
+    /// Ângulo somado à tangente (radianos), para formas que não apontam para +X
+    #[serde(default)]
+    pub rotation_offset: f32,
+}
+
+impl PathFollow {
+    /// Segue o caminho sem alterar a rotação
+    pub fn new(path: MotionPath) -> Self {
+        Self {
+            path,
+            auto_rotate: false,
+            rotation_offset: 0.0,
+        }
+    }
+
+    /// Segue o caminho girando para a tangente, com um ângulo adicional
+    pub fn rotated(path: MotionPath, rotation_offset: f32) -> Self {
+        Self {
+            path,
+            auto_rotate: true,
+            rotation_offset,
+        }
+    }
+
+    /// Posição e, com `auto_rotate`, rotação em uma fração do comprimento
+    pub fn pose(&self, progress: f32) -> (Vec2, Option<f32>) {
+        let (position, tangent) = self.path.sample_progress(progress);
+        let rotation = self
+            .auto_rotate
+            .then(|| tangent.y.atan2(tangent.x) + self.rotation_offset);
+        (position, rotation)
+    }
+}
@@ -0,0 +1,210 @@
+//! Tracks procedurais (ruído e osciladores)
+//!
+//! Uma `ProceduralTrack` gera um deslocamento a cada instante e o soma ao
+//! valor da propriedade: ao valor das tracks de keyframes da mesma propriedade
+//! ou, se não houver, ao valor da entidade quando a animação começou.
+//!
+//! A amplitude é dada como uma propriedade do mesmo tipo da animada (ex:
+//! `Position(Vec2::new(8.0, 4.0))` treme 8 px em X e 4 px em Y). Ruído e
+//! tremor usam sementes diferentes para cada componente, então X e Y se movem
+//! de forma independente; o resultado é o mesmo para a mesma semente.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! let animation = AnimationBuilder::new("flutuar")
+//!     .move_to(Vec2::new(200.0, 0.0), 2.0, Easing::EaseInOut)
+//!     .at(0.0)
+//!     .procedural(ProceduralTrack::noise(AnimatableProperty::Position(Vec2::splat(6.0)), 1.5, 7).lasting(2.0))
+//!     .procedural(ProceduralTrack::sine(AnimatableProperty::Rotation(0.1), 0.5))
+//!     .build();
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use super::easing::lerp;
+use super::keyframe::AnimatableProperty;
+
+/// Forma do sinal gerado por uma track procedural (valores entre -1 e 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Waveform {
+    /// Senoide
+    Sine,
+    /// Onda quadrada
+    Square,
+    /// Onda triangular
+    Triangle,
+    /// Dente de serra
+    Sawtooth,
+    /// Ruído de gradiente (Perlin 1D), suave
+    Noise,
+    /// Valores aleatórios mantidos por `1 / frequência` segundos
+    Jitter,
+}
+
+/// Track que soma um sinal procedural a uma propriedade
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProceduralTrack {
+    /// Amplitude de cada componente (o tipo define a propriedade animada)
+    pub amplitude: AnimatableProperty,
+    /// Forma do sinal
+    pub waveform: Waveform,
+    /// Frequência em ciclos por segundo
+    pub frequency: f32,
+    /// Fase inicial em ciclos (0.0 a 1.0)
+    #[serde(default)]
+    pub phase: f32,
+    /// Semente do ruído e do tremor
+    #[serde(default)]
+    pub seed: u32,
+    /// Tempo da timeline em que a track começa
+    #[serde(default)]
+    pub start: f32,
+    /// Duração da track (`None` = até o fim da timeline)
+    #[serde(default)]
+    pub duration: Option<f32>,
+    /// Se a amplitude diminui até zero no fim da duração
+    #[serde(default)]
+    pub decay: bool,
+    /// Valor da entidade quando a animação começou
+    #[serde(skip)]
+    pub(crate) base: Option<AnimatableProperty>,
+}
+
+impl ProceduralTrack {
+    /// Cria uma track com a forma de sinal dada
+    pub fn new(amplitude: AnimatableProperty, waveform: Waveform, frequency: f32) -> Self {
+        Self {
+            amplitude,
+            waveform,
+            frequency,
+            phase: 0.0,
+            seed: 0,
+            start: 0.0,
+            duration: None,
+            decay: false,
+            base: None,
+        }
+    }
+
+    /// Oscilador senoidal
+    pub fn sine(amplitude: AnimatableProperty, frequency: f32) -> Self {
+        Self::new(amplitude, Waveform::Sine, frequency)
+    }
+
+    /// Oscilador de onda quadrada
+    pub fn square(amplitude: AnimatableProperty, frequency: f32) -> Self {
+        Self::new(amplitude, Waveform::Square, frequency)
+    }
+
+    /// Oscilador de onda triangular
+    pub fn triangle(amplitude: AnimatableProperty, frequency: f32) -> Self {
+        Self::new(amplitude, Waveform::Triangle, frequency)
+    }
+
+    /// Ruído suave (wiggle)
+    pub fn noise(amplitude: AnimatableProperty, frequency: f32, seed: u32) -> Self {
+        Self::new(amplitude, Waveform::Noise, frequency).with_seed(seed)
+    }
+
+    /// Tremor aleatório com a semente dada
+    pub fn jitter(amplitude: AnimatableProperty, frequency: f32, seed: u32) -> Self {
+        Self::new(amplitude, Waveform::Jitter, frequency).with_seed(seed)
+    }
+
+    /// Define a semente
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Define a fase inicial (em ciclos)
+    pub fn with_phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Faz a track começar em um tempo da timeline
+    pub fn starting_at(mut self, start: f32) -> Self {
+        self.start = start.max(0.0);
+        self
+    }
+
+    /// Limita a duração da track
+    pub fn lasting(mut self, duration: f32) -> Self {
+        self.duration = Some(duration.max(0.0));
+        self
+    }
+
+    /// Faz a amplitude diminuir linearmente até zero no fim da duração, para
+    /// que a propriedade termine no valor sem deslocamento
+    pub fn decaying(mut self) -> Self {
+        self.decay = true;
+        self
+    }
+
+    /// Tempo em que a track termina, se tiver duração
+    pub fn end(&self) -> Option<f32> {
+        self.duration.map(|duration| self.start + duration)
+    }
+
+    /// Deslocamento no tempo da timeline (`None` fora do intervalo da track)
+    pub fn offset_at(&self, time: f32) -> Option<AnimatableProperty> {
+        if time < self.start || self.end().is_some_and(|end| time > end) {
+            return None;
+        }
+
+        let x = (time - self.start) * self.frequency + self.phase;
+        let envelope = match self.duration {
+            Some(duration) if self.decay && duration > 0.0 => 1.0 - (time - self.start) / duration,
+            _ => 1.0,
+        };
+        let amplitude = self.amplitude.to_vec4() * envelope;
+        let sample = Vec4::new(
+            self.sample(x, 0),
+            self.sample(x, 1),
+            self.sample(x, 2),
+            self.sample(x, 3),
+        );
+        Some(self.amplitude.with_vec4(amplitude * sample))
+    }
+
+    /// Valor do sinal para o componente `component`
+    fn sample(&self, x: f32, component: u32) -> f32 {
+        use std::f32::consts::TAU;
+        let cycle = x.rem_euclid(1.0);
+        match self.waveform {
+            Waveform::Sine => (x * TAU).sin(),
+            Waveform::Square => if cycle < 0.5 { 1.0 } else { -1.0 },
+            Waveform::Triangle => 1.0 - 4.0 * (cycle - 0.5).abs(),
+            Waveform::Sawtooth => 2.0 * cycle - 1.0,
+            Waveform::Noise => gradient_noise(x, self.seed.wrapping_add(component.wrapping_mul(7919))),
+            Waveform::Jitter => {
+                let step = x.floor() as i32;
+                random_signed(step, self.seed.wrapping_add(component.wrapping_mul(7919)))
+            }
+        }
+    }
+}
+
+/// Valor pseudoaleatório entre -1 e 1 para um inteiro e uma semente
+fn random_signed(i: i32, seed: u32) -> f32 {
+    let mut h = (i as u32).wrapping_mul(0x9E37_79B9) ^ seed.wrapping_mul(0x85EB_CA6B);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7FEB_352D);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846C_A68B);
+    h ^= h >> 16;
+    (h as f32 / u32::MAX as f32) * 2.0 - 1.0
+}
+
+/// Ruído de gradiente 1D (Perlin), aproximadamente entre -1 e 1
+fn gradient_noise(x: f32, seed: u32) -> f32 {
+    let i = x.floor();
+    let f = x - i;
+    let g0 = random_signed(i as i32, seed);
+    let g1 = random_signed(i as i32 + 1, seed);
+    // Curva de suavização quintica
+    let fade = f * f * f * (f * (f * 6.0 - 15.0) + 10.0);
+    // O máximo teórico de |ruído| é 0.5; escalar para usar toda a amplitude
+    lerp(g0 * f, g1 * (f - 1.0), fade) * 2.0
+}
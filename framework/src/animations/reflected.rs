@@ -0,0 +1,298 @@
+//! Animação de campos de componentes arbitrários via reflexão
+//!
+//! Um campo é identificado por `Componente::caminho`, onde `Componente` é o
+//! nome curto (ou completo) do tipo registrado e `caminho` segue a sintaxe de
+//! caminhos do `bevy_reflect` (ex: `Sprite::color`, `MyGauge::value`,
+//! `Transform::translation.z`).
+//!
+//! O componente precisa derivar `Reflect`, declarar `#[reflect(Component)]` e
+//! estar registrado com `app.register_type::<T>()`.
+
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::ecs::world::{FilteredEntityMut, FilteredEntityRef};
+use bevy::prelude::*;
+use bevy::reflect::{GetPath, PartialReflect, TypeRegistry};
+use serde::{Deserialize, Serialize};
+use super::color_space::ColorSpace;
+use super::easing::{Easing, lerp};
+use super::keyframe::AnimatableProperty;
+
+/// Valor de um campo animado, interpolado de acordo com o seu tipo
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FieldValue {
+    /// Número (campos `f32` ou `f64`)
+    Float(f32),
+    /// Vetor 2D
+    Vec2(Vec2),
+    /// Vetor 3D
+    Vec3(Vec3),
+    /// Vetor 4D
+    Vec4(Vec4),
+    /// Cor (campos `Color`, `LinearRgba` ou `Srgba`), interpolada em RGB linear
+    /// ou no espaço de cor da track
+    Color(Color),
+}
+
+impl FieldValue {
+    /// Interpola entre dois valores do mesmo tipo (cores em RGB linear)
+    pub fn interpolate(&self, other: &Self, t: f32, easing: &Easing) -> Option<FieldValue> {
+        self.interpolate_in(other, t, easing, ColorSpace::LinearRgb)
+    }
+
+    /// Interpola entre dois valores do mesmo tipo, misturando cores no espaço dado
+    pub fn interpolate_in(
+        &self,
+        other: &Self,
+        t: f32,
+        easing: &Easing,
+        color_space: ColorSpace,
+    ) -> Option<FieldValue> {
+        let eased = easing.apply(t);
+        let mix = |a: f32, b: f32| lerp(a, b, eased);
+        let mix4 = |a: Vec4, b: Vec4| Vec4::new(mix(a.x, b.x), mix(a.y, b.y), mix(a.z, b.z), mix(a.w, b.w));
+
+        match (self, other) {
+            (FieldValue::Float(a), FieldValue::Float(b)) => Some(FieldValue::Float(mix(*a, *b))),
+            (FieldValue::Vec2(a), FieldValue::Vec2(b)) => {
+                Some(FieldValue::Vec2(Vec2::new(mix(a.x, b.x), mix(a.y, b.y))))
+            }
+            (FieldValue::Vec3(a), FieldValue::Vec3(b)) => {
+                Some(FieldValue::Vec3(Vec3::new(mix(a.x, b.x), mix(a.y, b.y), mix(a.z, b.z))))
+            }
+            (FieldValue::Vec4(a), FieldValue::Vec4(b)) => Some(FieldValue::Vec4(mix4(*a, *b))),
+            (FieldValue::Color(a), FieldValue::Color(b)) => {
+                Some(FieldValue::Color(color_space.mix(*a, *b, eased)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Soma um delta do mesmo tipo
+    pub fn add(&self, delta: &Self) -> Option<FieldValue> {
+        match (self, delta) {
+            (FieldValue::Float(a), FieldValue::Float(b)) => Some(FieldValue::Float(a + b)),
+            (FieldValue::Vec2(a), FieldValue::Vec2(b)) => Some(FieldValue::Vec2(*a + *b)),
+            (FieldValue::Vec3(a), FieldValue::Vec3(b)) => Some(FieldValue::Vec3(*a + *b)),
+            (FieldValue::Vec4(a), FieldValue::Vec4(b)) => Some(FieldValue::Vec4(*a + *b)),
+            (FieldValue::Color(a), FieldValue::Color(b)) => {
+                let sum = a.to_linear().to_vec4() + b.to_linear().to_vec4();
+                Some(FieldValue::Color(Color::LinearRgba(LinearRgba::from_vec4(sum))))
+            }
+            _ => None,
+        }
+    }
+
+    /// Valor neutro do mesmo tipo (usado quando o campo não pode ser lido)
+    pub fn neutral(&self) -> FieldValue {
+        match self {
+            FieldValue::Float(_) => FieldValue::Float(0.0),
+            FieldValue::Vec2(_) => FieldValue::Vec2(Vec2::ZERO),
+            FieldValue::Vec3(_) => FieldValue::Vec3(Vec3::ZERO),
+            FieldValue::Vec4(_) => FieldValue::Vec4(Vec4::ZERO),
+            FieldValue::Color(_) => FieldValue::Color(Color::WHITE),
+        }
+    }
+
+    /// Componentes numéricos do valor (cores em RGB linear)
+    pub(crate) fn to_vec4(self) -> Vec4 {
+        match self {
+            FieldValue::Float(v) => Vec4::new(v, 0.0, 0.0, 0.0),
+            FieldValue::Vec2(v) => v.extend(0.0).extend(0.0),
+            FieldValue::Vec3(v) => v.extend(0.0),
+            FieldValue::Vec4(v) => v,
+            FieldValue::Color(c) => c.to_linear().to_vec4(),
+        }
+    }
+
+    /// Valor do mesmo tipo com os componentes numéricos `v`
+    pub(crate) fn with_vec4(&self, v: Vec4) -> FieldValue {
+        match self {
+            FieldValue::Float(_) => FieldValue::Float(v.x),
+            FieldValue::Vec2(_) => FieldValue::Vec2(v.truncate().truncate()),
+            FieldValue::Vec3(_) => FieldValue::Vec3(v.truncate()),
+            FieldValue::Vec4(_) => FieldValue::Vec4(v),
+            FieldValue::Color(_) => FieldValue::Color(Color::LinearRgba(LinearRgba::from_vec4(v))),
+        }
+    }
+
+    /// Lê um campo refletido como um valor do mesmo tipo que `self`
+    fn read_from(&self, field: &dyn PartialReflect) -> Option<FieldValue> {
+        match self {
+            FieldValue::Float(_) => field
+                .try_downcast_ref::<f32>()
+                .copied()
+                .or_else(|| field.try_downcast_ref::<f64>().map(|v| *v as f32))
+                .map(FieldValue::Float),
+            FieldValue::Vec2(_) => field.try_downcast_ref::<Vec2>().copied().map(FieldValue::Vec2),
+            FieldValue::Vec3(_) => field.try_downcast_ref::<Vec3>().copied().map(FieldValue::Vec3),
+            FieldValue::Vec4(_) => field.try_downcast_ref::<Vec4>().copied().map(FieldValue::Vec4),
+            FieldValue::Color(_) => field
+                .try_downcast_ref::<Color>()
+                .copied()
+                .or_else(|| field.try_downcast_ref::<LinearRgba>().map(|c| (*c).into()))
+                .or_else(|| field.try_downcast_ref::<Srgba>().map(|c| (*c).into()))
+                .map(FieldValue::Color),
+        }
+    }
+
+    /// Escreve o valor em um campo refletido, convertendo quando necessário
+    ///
+    /// Retorna `false` se o tipo do campo não for compatível.
+    fn write_to(&self, field: &mut dyn PartialReflect) -> bool {
+        match *self {
+            FieldValue::Float(value) => {
+                if let Some(target) = field.try_downcast_mut::<f32>() {
+                    *target = value;
+                } else if let Some(target) = field.try_downcast_mut::<f64>() {
+                    *target = value as f64;
+                } else {
+                    return false;
+                }
+            }
+            FieldValue::Vec2(value) => match field.try_downcast_mut::<Vec2>() {
+                Some(target) => *target = value,
+                None => return false,
+            },
+            FieldValue::Vec3(value) => match field.try_downcast_mut::<Vec3>() {
+                Some(target) => *target = value,
+                None => return false,
+            },
+            FieldValue::Vec4(value) => match field.try_downcast_mut::<Vec4>() {
+                Some(target) => *target = value,
+                None => return false,
+            },
+            FieldValue::Color(value) => {
+                if let Some(target) = field.try_downcast_mut::<Color>() {
+                    *target = value;
+                } else if let Some(target) = field.try_downcast_mut::<LinearRgba>() {
+                    *target = value.to_linear();
+                } else if let Some(target) = field.try_downcast_mut::<Srgba>() {
+                    *target = value.to_srgba();
+                } else {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl From<f32> for FieldValue {
+    fn from(value: f32) -> Self {
+        FieldValue::Float(value)
+    }
+}
+
+impl From<Vec2> for FieldValue {
+    fn from(value: Vec2) -> Self {
+        FieldValue::Vec2(value)
+    }
+}
+
+impl From<Vec3> for FieldValue {
+    fn from(value: Vec3) -> Self {
+        FieldValue::Vec3(value)
+    }
+}
+
+impl From<Vec4> for FieldValue {
+    fn from(value: Vec4) -> Self {
+        FieldValue::Vec4(value)
+    }
+}
+
+impl From<Color> for FieldValue {
+    fn from(value: Color) -> Self {
+        FieldValue::Color(value)
+    }
+}
+
+/// Separa `Componente::caminho` no nome do componente e no caminho do campo
+///
+/// O nome do componente pode ser um caminho completo (`meu_jogo::MyGauge`);
+/// o caminho do campo começa depois do último `::`.
+pub fn split_field_path(path: &str) -> Option<(&str, &str)> {
+    let (component, field) = path.rsplit_once("::")?;
+    if component.is_empty() || field.is_empty() {
+        return None;
+    }
+    Some((component, field))
+}
+
+/// Encontra o `ReflectComponent` de um componente pelo nome curto ou completo
+fn reflect_component<'a>(registry: &'a TypeRegistry, name: &str) -> Option<&'a ReflectComponent> {
+    registry
+        .get_with_short_type_path(name)
+        .or_else(|| registry.get_with_type_path(name))
+        .and_then(|registration| registration.data::<ReflectComponent>())
+}
+
+/// Lê o valor atual de um campo da entidade, com o mesmo tipo de `kind`
+pub fn read_field<'a>(
+    entity: impl Into<FilteredEntityRef<'a>>,
+    registry: &TypeRegistry,
+    path: &str,
+    kind: &FieldValue,
+) -> Option<FieldValue> {
+    let (component, field) = split_field_path(path)?;
+    let reflected = reflect_component(registry, component)?.reflect(entity)?;
+    let value = reflected.reflect_path(field).ok()?;
+    kind.read_from(value)
+}
+
+/// Escreve um valor em um campo da entidade
+///
+/// Retorna `false` se o componente não estiver registrado, a entidade não o
+/// tiver ou o campo não existir ou tiver tipo incompatível.
+pub fn write_field<'a>(
+    entity: impl Into<FilteredEntityMut<'a>>,
+    registry: &TypeRegistry,
+    path: &str,
+    value: &FieldValue,
+) -> bool {
+    let Some((component, field)) = split_field_path(path) else {
+        return false;
+    };
+    let Some(mut reflected) = reflect_component(registry, component).and_then(|c| c.reflect_mut(entity)) else {
+        return false;
+    };
+
+    // Evitar marcar o componente como alterado quando o valor não muda
+    let unchanged = reflected
+        .reflect_path(field)
+        .ok()
+        .and_then(|current| value.read_from(current))
+        .is_some_and(|current| current == *value);
+    if unchanged {
+        return true;
+    }
+
+    match reflected.bypass_change_detection().reflect_path_mut(field) {
+        Ok(target) => {
+            let written = value.write_to(target);
+            if written {
+                reflected.set_changed();
+            }
+            written
+        }
+        Err(_) => false,
+    }
+}
+
+/// Escreve uma propriedade animada no campo correspondente da entidade
+///
+/// Trata `Field` e `Custom` cujo nome é um caminho `Componente::campo` (como
+/// um `FieldValue::Float`); as demais propriedades são ignoradas.
+pub(crate) fn write_property<'a>(
+    entity: impl Into<FilteredEntityMut<'a>>,
+    registry: &TypeRegistry,
+    property: &AnimatableProperty,
+) -> bool {
+    match property {
+        AnimatableProperty::Field(path, value) => write_field(entity, registry, path, value),
+        AnimatableProperty::Custom(path, value) => {
+            write_field(entity, registry, path, &FieldValue::Float(*value))
+        }
+        _ => false,
+    }
+}
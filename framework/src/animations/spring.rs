@@ -0,0 +1,384 @@
+//! Animações por molas físicas
+//!
+//! Em vez de uma duração fixa, cada propriedade é puxada em direção ao alvo
+//! por uma mola amortecida (rigidez, amortecimento e massa). O alvo pode ser
+//! trocado a qualquer momento: a mola continua da posição e velocidade atuais,
+//! sem saltos. A animação termina quando a velocidade e a distância até o alvo
+//! ficam abaixo dos limites de repouso; nesse momento o evento `SpringSettled`
+//! é emitido.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! // Soltar uma forma arrastada: voltar para a origem mantendo o impulso
+//! commands.entity(entity).insert(
+//!     SpringAnimation::new()
+//!         .to(AnimatableProperty::Position(Vec2::ZERO), SpringConfig::wobbly())
+//!         .with_velocity(AnimatableProperty::Position(drag_velocity)),
+//! );
+//!
+//! // Mudar o alvo no meio do movimento
+//! spring.set_target(AnimatableProperty::Position(new_position));
+//! ```
+
+use bevy::ecs::world::{EntityMutExcept, EntityRefExcept};
+use bevy::prelude::*;
+use bevy::sprite::MeshMaterial2d;
+use std::collections::HashMap;
+use super::animation_system::{apply_entity_property, entity_color, read_entity_property};
+use super::clock::AnimationClock;
+use super::keyframe::AnimatableProperty;
+use super::reflected::write_property;
+use crate::geometrics::ShapeMaterial;
+
+/// Passo máximo de integração (segundos), para manter molas rígidas estáveis
+const SPRING_STEP: f32 = 1.0 / 240.0;
+
+/// Parâmetros físicos de uma mola
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringConfig {
+    /// Rigidez: quanto maior, mais rápido a mola puxa em direção ao alvo
+    pub stiffness: f32,
+    /// Amortecimento: quanto maior, menos a mola oscila
+    pub damping: f32,
+    /// Massa do objeto preso à mola
+    pub mass: f32,
+    /// Velocidade abaixo da qual a mola pode entrar em repouso
+    pub rest_velocity: f32,
+    /// Distância até o alvo abaixo da qual a mola pode entrar em repouso
+    pub rest_distance: f32,
+}
+
+impl Default for SpringConfig {
+    fn default() -> Self {
+        Self {
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            rest_velocity: 0.01,
+            rest_distance: 0.01,
+        }
+    }
+}
+
+impl SpringConfig {
+    /// Cria uma configuração com os limites de repouso padrão
+    pub fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass,
+            ..default()
+        }
+    }
+
+    /// Mola suave, sem oscilação perceptível
+    pub fn gentle() -> Self {
+        Self::new(120.0, 14.0, 1.0)
+    }
+
+    /// Mola elástica, com oscilação visível
+    pub fn wobbly() -> Self {
+        Self::new(180.0, 12.0, 1.0)
+    }
+
+    /// Mola rígida e rápida
+    pub fn stiff() -> Self {
+        Self::new(210.0, 20.0, 1.0)
+    }
+
+    /// Mola lenta e bem amortecida
+    pub fn slow() -> Self {
+        Self::new(280.0, 60.0, 1.0)
+    }
+
+    /// Define os limites de repouso (velocidade e distância)
+    pub fn with_rest(mut self, rest_velocity: f32, rest_distance: f32) -> Self {
+        self.rest_velocity = rest_velocity;
+        self.rest_distance = rest_distance;
+        self
+    }
+}
+
+/// Mola de uma propriedade
+#[derive(Debug, Clone)]
+struct Spring {
+    target: AnimatableProperty,
+    /// Valor atual; `None` até ser lido da entidade
+    value: Option<Vec4>,
+    velocity: Vec4,
+    config: SpringConfig,
+    settled: bool,
+    /// Se o valor mudou no último quadro e precisa ser aplicado
+    moved: bool,
+}
+
+impl Spring {
+    /// Avança a simulação; retorna `true` se a mola entrou em repouso neste passo
+    fn step(&mut self, delta: f32) -> bool {
+        self.moved = false;
+        let Some(mut value) = self.value else {
+            return false;
+        };
+        if self.settled {
+            return false;
+        }
+
+        let target = self.target.to_vec4();
+        let config = self.config;
+        let mass = config.mass.max(f32::EPSILON);
+
+        // Euler semi-implícito com subpassos de tamanho igual
+        let steps = (delta / SPRING_STEP).ceil().max(1.0) as u32;
+        let dt = delta / steps as f32;
+        for _ in 0..steps {
+            let force = -config.stiffness * (value - target) - config.damping * self.velocity;
+            self.velocity += force / mass * dt;
+            value += self.velocity * dt;
+        }
+
+        self.moved = true;
+        let at_rest = self.velocity.length() < config.rest_velocity
+            && value.distance(target) < config.rest_distance;
+        if at_rest {
+            value = target;
+            self.velocity = Vec4::ZERO;
+            self.settled = true;
+        }
+        self.value = Some(value);
+        at_rest
+    }
+
+    fn current(&self) -> Option<AnimatableProperty> {
+        self.value.map(|value| self.target.with_vec4(value))
+    }
+}
+
+/// Componente que anima propriedades da entidade com molas
+///
+/// Cada propriedade tem uma mola própria, identificada pelo nome da track
+/// (`"position"`, `"color"`, o caminho de um campo refletido...). O valor
+/// inicial é lido da entidade no primeiro quadro.
+#[derive(Component, Debug, Clone, Default)]
+pub struct SpringAnimation {
+    springs: HashMap<String, Spring>,
+}
+
+impl SpringAnimation {
+    /// Cria um componente sem molas
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adiciona (ou redireciona) uma mola para o valor alvo
+    pub fn to(mut self, target: AnimatableProperty, config: SpringConfig) -> Self {
+        self.set_target_with(target, config);
+        self
+    }
+
+    /// Define a velocidade inicial da mola da mesma propriedade
+    ///
+    /// A velocidade usa as unidades da propriedade por segundo (ex:
+    /// `Position(Vec2)` em pixels por segundo).
+    pub fn with_velocity(mut self, velocity: AnimatableProperty) -> Self {
+        self.set_velocity(velocity);
+        self
+    }
+
+    /// Muda o alvo da mola da propriedade, mantendo o valor e a velocidade atuais
+    ///
+    /// Se ainda não houver mola para a propriedade, uma nova é criada com a
+    /// configuração padrão.
+    pub fn set_target(&mut self, target: AnimatableProperty) {
+        let config = self
+            .springs
+            .get(&target.track_name())
+            .map(|spring| spring.config)
+            .unwrap_or_default();
+        self.set_target_with(target, config);
+    }
+
+    /// Muda o alvo e a configuração da mola da propriedade
+    pub fn set_target_with(&mut self, target: AnimatableProperty, config: SpringConfig) {
+        let spring = self.springs.entry(target.track_name()).or_insert_with(|| Spring {
+            target: target.clone(),
+            value: None,
+            velocity: Vec4::ZERO,
+            config,
+            settled: false,
+            moved: false,
+        });
+        spring.target = target;
+        spring.config = config;
+        spring.settled = false;
+    }
+
+    /// Substitui a velocidade atual da mola da mesma propriedade
+    pub fn set_velocity(&mut self, velocity: AnimatableProperty) {
+        if let Some(spring) = self.springs.get_mut(&velocity.track_name()) {
+            spring.velocity = velocity.to_vec4();
+            spring.settled = false;
+        }
+    }
+
+    /// Remove a mola de uma propriedade (o valor atual é mantido na entidade)
+    pub fn remove(&mut self, track: &str) {
+        self.springs.remove(track);
+    }
+
+    /// Valor atual da propriedade, se já tiver sido lido da entidade
+    pub fn value(&self, track: &str) -> Option<AnimatableProperty> {
+        self.springs.get(track).and_then(Spring::current)
+    }
+
+    /// Velocidade atual da propriedade
+    pub fn velocity(&self, track: &str) -> Option<AnimatableProperty> {
+        self.springs
+            .get(track)
+            .map(|spring| spring.target.with_vec4(spring.velocity))
+    }
+
+    /// Alvo atual da propriedade
+    pub fn target(&self, track: &str) -> Option<&AnimatableProperty> {
+        self.springs.get(track).map(|spring| &spring.target)
+    }
+
+    /// Verifica se a mola da propriedade está em repouso
+    pub fn is_track_settled(&self, track: &str) -> bool {
+        self.springs.get(track).is_none_or(|spring| spring.settled)
+    }
+
+    /// Verifica se todas as molas estão em repouso
+    pub fn is_settled(&self) -> bool {
+        self.springs.values().all(|spring| spring.settled)
+    }
+
+    /// Valores que mudaram no último quadro
+    fn moved_values(&self) -> impl Iterator<Item = AnimatableProperty> + '_ {
+        self.springs
+            .values()
+            .filter(|spring| spring.moved)
+            .filter_map(Spring::current)
+    }
+
+    /// Descarta os valores pendentes de aplicação
+    fn clear_moved(&mut self) {
+        for spring in self.springs.values_mut() {
+            spring.moved = false;
+        }
+    }
+}
+
+/// Emitido quando a mola de uma propriedade entra em repouso
+#[derive(Event, Debug, Clone)]
+pub struct SpringSettled {
+    /// Entidade animada
+    pub entity: Entity,
+    /// Nome da track da propriedade
+    pub track: String,
+}
+
+/// Molas com o estado da entidade de onde os valores iniciais são lidos
+type SpringReadQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut SpringAnimation,
+        &'static Transform,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+        Option<&'static MeshMaterial2d<ShapeMaterial>>,
+        EntityRefExcept<'static, SpringAnimation>,
+    ),
+>;
+
+/// Molas com o `Transform` e o material onde os valores são aplicados
+type SpringWriteQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static SpringAnimation,
+        &'static mut Transform,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+        Option<&'static MeshMaterial2d<ShapeMaterial>>,
+    ),
+>;
+
+/// Sistema que lê os valores iniciais e avança a simulação das molas
+pub fn update_springs(
+    clock: Res<AnimationClock>,
+    mut query: SpringReadQuery,
+    materials: Option<Res<Assets<ColorMaterial>>>,
+    shape_materials: Option<Res<Assets<ShapeMaterial>>>,
+    registry: Res<AppTypeRegistry>,
+    mut settled: EventWriter<SpringSettled>,
+) {
+    let delta = clock.delta();
+    let registry = registry.read();
+
+    for (entity_id, mut animation, transform, material_component, shape_material_component, entity) in
+        query.iter_mut()
+    {
+        // Molas em repouso não são mais aplicadas; o valor final já foi escrito
+        if animation.is_settled() {
+            animation.bypass_change_detection().clear_moved();
+            continue;
+        }
+
+        let color = entity_color(
+            material_component,
+            shape_material_component,
+            materials.as_deref(),
+            shape_materials.as_deref(),
+        );
+
+        for (track, spring) in animation.springs.iter_mut() {
+            // Começar do valor atual da entidade (ou do alvo, se não puder ser lido)
+            if spring.value.is_none() {
+                let current = read_entity_property(&spring.target, transform, color, &entity, &registry)
+                    .unwrap_or_else(|| spring.target.clone());
+                spring.value = Some(current.to_vec4());
+            }
+
+            if spring.step(delta) {
+                settled.write(SpringSettled {
+                    entity: entity_id,
+                    track: track.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Sistema que aplica os valores das molas ao `Transform` e ao material
+pub fn apply_spring_properties(
+    mut query: SpringWriteQuery,
+    mut materials: Option<ResMut<Assets<ColorMaterial>>>,
+    mut shape_materials: Option<ResMut<Assets<ShapeMaterial>>>,
+) {
+    for (animation, mut transform, material_component, shape_material_component) in query.iter_mut() {
+        for property in animation.moved_values() {
+            apply_entity_property(
+                &property,
+                &mut transform,
+                material_component,
+                shape_material_component,
+                materials.as_deref_mut(),
+                shape_materials.as_deref_mut(),
+            );
+        }
+    }
+}
+
+/// Sistema que aplica os valores das molas a campos refletidos
+pub fn apply_spring_fields(
+    mut query: Query<(&SpringAnimation, EntityMutExcept<SpringAnimation>)>,
+    registry: Res<AppTypeRegistry>,
+) {
+    let registry = registry.read();
+
+    for (animation, entity) in query.iter_mut() {
+        for property in animation.moved_values() {
+            write_property(&entity, &registry, &property);
+        }
+    }
+}
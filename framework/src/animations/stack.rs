@@ -0,0 +1,489 @@
+//! Pilha de animações com mistura de camadas
+//!
+//! Um `AnimationStack` reproduz várias timelines na mesma entidade. As camadas
+//! são avaliadas de baixo para cima a partir dos valores de repouso da entidade
+//! (lidos quando cada propriedade é animada pela primeira vez):
+//!
+//! - `LayerBlend::Override` mistura o valor da camada com o resultado das
+//!   camadas de baixo de acordo com o peso;
+//! - `LayerBlend::Additive` soma a diferença entre o valor atual da camada e o
+//!   valor dela no tempo 0, multiplicada pelo peso.
+//!
+//! Propriedades são combinadas pelo tipo (`"position"`, `"rotation"`, campo
+//! refletido...), então uma track de caminho e uma de posição se misturam.
+//! Os eventos das timelines das camadas não são emitidos; use
+//! `AnimationComponent` quando precisar deles.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! // Pulso contínuo com um tremor disparado pelo usuário por cima
+//! commands.entity(entity).insert(
+//!     AnimationStack::new().with_layer(AnimationLayer::new("idle", AnimationPresets::pulse(1.1, 1.0))),
+//! );
+//!
+//! stack.push(AnimationLayer::additive("hit", AnimationPresets::shake(10.0, 0.4)).remove_when_finished());
+//!
+//! // Trocar a animação de uma camada suavemente
+//! stack.crossfade("idle", AnimationPresets::bounce(20.0, 0.8), 0.3);
+//! ```
+
+use bevy::ecs::world::{EntityMutExcept, EntityRefExcept};
+use bevy::prelude::*;
+use bevy::sprite::MeshMaterial2d;
+use super::animation_system::{apply_entity_property, entity_color, read_entity_property};
+use super::clock::AnimationClock;
+use super::easing::{lerp, Easing};
+use super::keyframe::AnimatableProperty;
+use super::reflected::write_property;
+use super::timeline::AnimationTimeline;
+use crate::geometrics::ShapeMaterial;
+
+/// Como uma camada é combinada com as camadas abaixo dela
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayerBlend {
+    /// Substitui o valor de baixo (misturando pelo peso)
+    #[default]
+    Override,
+    /// Soma o deslocamento da camada em relação ao seu valor inicial
+    Additive,
+}
+
+/// Transição de peso de uma camada
+#[derive(Debug, Clone, Copy)]
+struct LayerFade {
+    from: f32,
+    to: f32,
+    duration: f32,
+    elapsed: f32,
+    /// Remover a camada quando a transição terminar
+    remove: bool,
+    /// Em uma troca de timeline, manter o peso inicial nas propriedades que a
+    /// camada substituta também anima
+    hold_shared: bool,
+}
+
+impl LayerFade {
+    fn weight(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return self.to;
+        }
+        lerp(self.from, self.to, (self.elapsed / self.duration).clamp(0.0, 1.0))
+    }
+
+    fn is_done(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// Camada de um `AnimationStack`
+pub struct AnimationLayer {
+    /// Nome da camada
+    pub name: String,
+    /// Timeline reproduzida pela camada
+    pub timeline: AnimationTimeline,
+    /// Peso da camada (0.0 a 1.0)
+    pub weight: f32,
+    /// Modo de mistura
+    pub blend: LayerBlend,
+    /// Se a camada é removida quando a timeline termina
+    pub auto_remove: bool,
+    fade: Option<LayerFade>,
+    /// Valores da timeline no tempo 0, referência das camadas aditivas
+    reference: Option<Vec<AnimatableProperty>>,
+}
+
+impl AnimationLayer {
+    /// Camada que substitui os valores de baixo, com peso 1.0
+    pub fn new(name: impl Into<String>, timeline: AnimationTimeline) -> Self {
+        Self {
+            name: name.into(),
+            timeline,
+            weight: 1.0,
+            blend: LayerBlend::Override,
+            auto_remove: false,
+            fade: None,
+            reference: None,
+        }
+    }
+
+    /// Camada aditiva, com peso 1.0
+    pub fn additive(name: impl Into<String>, timeline: AnimationTimeline) -> Self {
+        Self {
+            blend: LayerBlend::Additive,
+            ..Self::new(name, timeline)
+        }
+    }
+
+    /// Define o peso da camada
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Remove a camada quando a timeline terminar
+    pub fn remove_when_finished(mut self) -> Self {
+        self.auto_remove = true;
+        self
+    }
+
+    /// Peso atual, considerando transições em andamento
+    pub fn current_weight(&self) -> f32 {
+        self.fade.map_or(self.weight, |fade| fade.weight())
+    }
+
+    /// Verifica se a camada está saindo da pilha
+    fn is_leaving(&self) -> bool {
+        self.fade.is_some_and(|fade| fade.remove)
+    }
+
+    fn start_fade(&mut self, to: f32, duration: f32, remove: bool) {
+        self.fade = Some(LayerFade {
+            from: self.current_weight(),
+            to,
+            duration,
+            elapsed: 0.0,
+            remove,
+            hold_shared: false,
+        });
+    }
+
+    /// Peso da camada para uma propriedade
+    ///
+    /// `shared` indica se a camada que a substitui também anima a propriedade.
+    fn weight_for(&self, shared: bool) -> f32 {
+        match self.fade {
+            Some(fade) if fade.hold_shared && shared => fade.from,
+            _ => self.current_weight(),
+        }
+    }
+}
+
+/// Componente que reproduz e mistura várias timelines na entidade
+///
+/// As timelines são iniciadas ao serem adicionadas. Não use junto com
+/// `AnimationComponent` nas mesmas propriedades.
+#[derive(Component, Default)]
+pub struct AnimationStack {
+    layers: Vec<AnimationLayer>,
+    /// Valores de repouso da entidade, um por tipo de propriedade
+    base: Vec<AnimatableProperty>,
+    /// Valores misturados no último quadro
+    output: Vec<AnimatableProperty>,
+}
+
+impl AnimationStack {
+    /// Cria uma pilha vazia
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adiciona uma camada no topo
+    pub fn with_layer(mut self, layer: AnimationLayer) -> Self {
+        self.push(layer);
+        self
+    }
+
+    /// Adiciona uma camada no topo, ou substitui a camada de mesmo nome
+    pub fn push(&mut self, mut layer: AnimationLayer) {
+        layer.timeline.play();
+        match self.index_of(&layer.name) {
+            Some(index) => self.layers[index] = layer,
+            None => self.layers.push(layer),
+        }
+    }
+
+    /// Remove uma camada imediatamente
+    pub fn remove(&mut self, name: &str) {
+        self.layers.retain(|layer| layer.name != name);
+    }
+
+    /// Camada pelo nome
+    pub fn layer(&self, name: &str) -> Option<&AnimationLayer> {
+        self.index_of(name).map(|index| &self.layers[index])
+    }
+
+    /// Camada pelo nome (mutável)
+    pub fn layer_mut(&mut self, name: &str) -> Option<&mut AnimationLayer> {
+        self.index_of(name).map(|index| &mut self.layers[index])
+    }
+
+    /// Camadas da pilha, de baixo para cima
+    pub fn layers(&self) -> &[AnimationLayer] {
+        &self.layers
+    }
+
+    /// Define o peso de uma camada imediatamente
+    pub fn set_weight(&mut self, name: &str, weight: f32) {
+        if let Some(layer) = self.layer_mut(name) {
+            layer.weight = weight.clamp(0.0, 1.0);
+            layer.fade = None;
+        }
+    }
+
+    /// Muda o peso de uma camada gradualmente
+    pub fn fade_weight(&mut self, name: &str, weight: f32, duration: f32) {
+        if let Some(layer) = self.layer_mut(name) {
+            let weight = weight.clamp(0.0, 1.0);
+            layer.start_fade(weight, duration, false);
+            layer.weight = weight;
+        }
+    }
+
+    /// Reduz o peso de uma camada até zero e a remove
+    pub fn fade_out(&mut self, name: &str, duration: f32) {
+        if let Some(layer) = self.layer_mut(name) {
+            layer.start_fade(0.0, duration, true);
+        }
+    }
+
+    /// Troca a timeline de uma camada com uma transição suave
+    ///
+    /// A nova timeline entra acima da antiga com peso crescente; a antiga é
+    /// removida quando a transição termina. Se a camada não existir, ela é
+    /// criada (`Override`, peso 1.0) entrando gradualmente.
+    pub fn crossfade(&mut self, name: &str, timeline: AnimationTimeline, duration: f32) {
+        let mut layer = AnimationLayer::new(name, timeline);
+        layer.timeline.play();
+
+        let position = match self.index_of(name) {
+            Some(index) => {
+                let old = &mut self.layers[index];
+                layer.blend = old.blend;
+                layer.weight = old.weight;
+                old.start_fade(0.0, duration, true);
+                // A nova camada esconde a antiga nas propriedades em comum
+                // conforme entra; nas demais a antiga volta ao repouso
+                if let Some(fade) = old.fade.as_mut() {
+                    fade.hold_shared = old.blend == LayerBlend::Override;
+                }
+                index + 1
+            }
+            None => self.layers.len(),
+        };
+
+        layer.fade = Some(LayerFade {
+            from: 0.0,
+            to: layer.weight,
+            duration,
+            elapsed: 0.0,
+            remove: false,
+            hold_shared: false,
+        });
+        self.layers.insert(position, layer);
+    }
+
+    /// Lê novamente os valores de repouso da entidade no próximo quadro
+    ///
+    /// Útil depois de mover a entidade por fora da pilha.
+    pub fn reset_base(&mut self) {
+        self.base.clear();
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.layers
+            .iter()
+            .rposition(|layer| layer.name == name && !layer.is_leaving())
+    }
+
+    /// Avança as timelines e as transições de peso
+    fn advance(&mut self, delta: f32) {
+        for layer in self.layers.iter_mut() {
+            layer.timeline.update(delta);
+            layer.timeline.drain_events();
+
+            if let Some(fade) = layer.fade.as_mut() {
+                fade.elapsed += delta;
+                if fade.is_done() && !fade.remove {
+                    layer.weight = fade.to;
+                    layer.fade = None;
+                }
+            }
+        }
+    }
+
+    /// Remove as camadas que terminaram de sair ou cujas timelines terminaram
+    ///
+    /// Chamado depois da mistura, para que o último valor delas seja aplicado.
+    fn remove_finished(&mut self) {
+        self.layers.retain(|layer| {
+            let faded_out = layer.fade.is_some_and(|fade| fade.remove && fade.is_done());
+            let finished = layer.auto_remove && layer.timeline.is_finished();
+            !faded_out && !finished
+        });
+    }
+}
+
+/// Garante que o valor de repouso da propriedade foi lido da entidade
+fn ensure_base(
+    base: &mut Vec<AnimatableProperty>,
+    property: &AnimatableProperty,
+    read: &impl Fn(&AnimatableProperty) -> Option<AnimatableProperty>,
+) {
+    if find_same(base, property).is_none() {
+        if let Some(value) = read(property) {
+            base.push(value);
+        }
+    }
+}
+
+/// Valor da mesma propriedade em uma lista pequena (uma entrada por tipo)
+fn find_same<'a>(values: &'a [AnimatableProperty], property: &AnimatableProperty) -> Option<&'a AnimatableProperty> {
+    values.iter().find(|value| value.same_track(property))
+}
+
+/// Pilhas com o estado da entidade de onde os valores base são lidos
+type StackReadQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut AnimationStack,
+        &'static Transform,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+        Option<&'static MeshMaterial2d<ShapeMaterial>>,
+        EntityRefExcept<'static, AnimationStack>,
+    ),
+>;
+
+/// Pilhas com o `Transform` e o material onde os valores são aplicados
+type StackWriteQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static AnimationStack,
+        &'static mut Transform,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+        Option<&'static MeshMaterial2d<ShapeMaterial>>,
+    ),
+>;
+
+/// Sistema que avança as pilhas e mistura os valores das camadas
+pub fn update_animation_stacks(
+    clock: Res<AnimationClock>,
+    mut query: StackReadQuery,
+    materials: Option<Res<Assets<ColorMaterial>>>,
+    shape_materials: Option<Res<Assets<ShapeMaterial>>>,
+    registry: Res<AppTypeRegistry>,
+) {
+    let delta = clock.delta();
+    let registry = registry.read();
+
+    for (mut stack, transform, material_component, shape_material_component, entity) in query.iter_mut() {
+        stack.advance(delta);
+
+        let color = entity_color(
+            material_component,
+            shape_material_component,
+            materials.as_deref(),
+            shape_materials.as_deref(),
+        );
+        let read = |property: &AnimatableProperty| {
+            read_entity_property(property, transform, color, &entity, &registry)
+        };
+
+        let AnimationStack { layers, base, output } = &mut *stack;
+
+        // Resolver os valores iniciais das timelines a partir do repouso da entidade
+        for layer in layers.iter_mut() {
+            if !layer.timeline.start_values_resolved {
+                for track in layer.timeline.tracks.values() {
+                    if let Some(keyframe) = track.keyframes.first() {
+                        ensure_base(base, &keyframe.property, &read);
+                    }
+                }
+                layer.timeline.resolve_start_values(|property| find_same(base, property).cloned());
+                layer.reference = None;
+            }
+            
+            // A referência das camadas aditivas só muda com os valores iniciais
+            if layer.blend == LayerBlend::Additive && layer.reference.is_none() {
+                let mut reference = Vec::new();
+                layer.timeline.for_each_value_at(0.0, |value| reference.push(value));
+                layer.reference = Some(reference);
+            }
+        }
+
+        // Misturar no buffer do quadro anterior, sem alocar
+        output.clear();
+        for (index, layer) in layers.iter().enumerate() {
+            // Camada que substitui esta em uma troca de timeline
+            let replacement = layer
+                .is_leaving()
+                .then(|| {
+                    (index + 1..layers.len())
+                        .find(|&other| layers[other].name == layer.name && !layers[other].is_leaving())
+                })
+                .flatten();
+
+            layer.timeline.for_each_current_value(|value| {
+                let shared = replacement.is_some_and(|other| layers[other].timeline.animates(&value));
+                // Com peso zero a camada ainda escreve o valor de baixo, para que a
+                // propriedade volte ao repouso quando a camada sai
+                let weight = layer.weight_for(shared);
+
+                ensure_base(base, &value, &read);
+                let slot = output.iter().position(|blended| blended.same_track(&value));
+                let below = slot.map(|i| &output[i]).or_else(|| find_same(base, &value));
+
+                let result = match (layer.blend, below) {
+                    (_, None) => value,
+                    (LayerBlend::Override, Some(below)) => {
+                        if weight >= 1.0 {
+                            value
+                        } else {
+                            below.interpolate(&value, weight, &Easing::Linear).unwrap_or(value)
+                        }
+                    }
+                    (LayerBlend::Additive, Some(below)) => {
+                        let start = layer
+                            .reference
+                            .as_deref()
+                            .and_then(|reference| find_same(reference, &value))
+                            .unwrap_or(&value);
+                        let offset = value.to_vec4() - start.to_vec4();
+                        below.with_vec4(below.to_vec4() + offset * weight)
+                    }
+                };
+                match slot {
+                    Some(i) => output[i] = result,
+                    None => output.push(result),
+                }
+            });
+        }
+
+        stack.remove_finished();
+    }
+}
+
+/// Sistema que aplica os valores misturados ao `Transform` e ao material
+pub fn apply_animation_stacks(
+    mut query: StackWriteQuery,
+    mut materials: Option<ResMut<Assets<ColorMaterial>>>,
+    mut shape_materials: Option<ResMut<Assets<ShapeMaterial>>>,
+) {
+    for (stack, mut transform, material_component, shape_material_component) in query.iter_mut() {
+        for property in &stack.output {
+            apply_entity_property(
+                property,
+                &mut transform,
+                material_component,
+                shape_material_component,
+                materials.as_deref_mut(),
+                shape_materials.as_deref_mut(),
+            );
+        }
+    }
+}
+
+/// Sistema que aplica os valores misturados a campos refletidos
+pub fn apply_animation_stack_fields(
+    mut query: Query<(&AnimationStack, EntityMutExcept<AnimationStack>)>,
+    registry: Res<AppTypeRegistry>,
+) {
+    let registry = registry.read();
+
+    for (stack, entity) in query.iter_mut() {
+        for property in &stack.output {
+            write_property(&entity, &registry, property);
+        }
+    }
+}
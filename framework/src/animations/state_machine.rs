@@ -0,0 +1,348 @@
+//! Máquina de estados de animação
+//!
+//! Cada estado tem sua própria timeline (normalmente em loop) e as transições
+//! entre estados são disparadas por eventos de interação (`SelectEvent`,
+//! `DeselectEvent`, `StartDragEvent`, `StopDragEvent`) ou por parâmetros
+//! definidos pelo usuário. A troca de timeline é feita com cross-fade na
+//! camada `"state"` de um `AnimationStack`, inserido automaticamente.
+//!
+//! Os eventos de interação também atualizam os parâmetros `"selected"` e
+//! `"dragging"`.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! commands.entity(entity).insert(
+//!     AnimationStateMachine::new("idle")
+//!         .state("idle", AnimationPresets::pulse(1.05, 1.5))
+//!         .state("hovered", AnimationBuilder::new("hovered").scale_to(1.2, 0.2, Easing::EaseOut).build())
+//!         .state("selected", AnimationPresets::spin(1.0))
+//!         .transition("idle", "hovered", TransitionCondition::Bool("hovered".into(), true), 0.15)
+//!         .transition("hovered", "idle", TransitionCondition::Bool("hovered".into(), false), 0.3)
+//!         .transition_from_any("selected", TransitionCondition::Selected, 0.2)
+//!         .transition("selected", "idle", TransitionCondition::Deselected, 0.4),
+//! );
+//!
+//! // Em um sistema do usuário
+//! machine.set_bool("hovered", cursor_over_shape);
+//! ```
+
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use super::asset::TimelineDefinition;
+use super::stack::{AnimationLayer, AnimationStack};
+use super::timeline::AnimationTimeline;
+use crate::interactions::{DeselectEvent, SelectEvent, StartDragEvent, StopDragEvent};
+
+/// Nome da camada do `AnimationStack` controlada pela máquina de estados
+pub const STATE_LAYER: &str = "state";
+
+/// Condição para uma transição acontecer
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransitionCondition {
+    /// A entidade foi selecionada neste quadro
+    Selected,
+    /// A entidade foi desselecionada neste quadro
+    Deselected,
+    /// A entidade começou a ser arrastada neste quadro
+    DragStarted,
+    /// A entidade parou de ser arrastada neste quadro
+    DragStopped,
+    /// A timeline do estado atual terminou
+    Finished,
+    /// Parâmetro booleano com o valor dado (parâmetros ausentes valem `false`)
+    Bool(String, bool),
+    /// Parâmetro numérico maior que o valor dado
+    Greater(String, f32),
+    /// Parâmetro numérico menor que o valor dado
+    Less(String, f32),
+    /// Gatilho disparado com `trigger` (consumido pela transição)
+    Trigger(String),
+    /// Todas as condições
+    All(Vec<TransitionCondition>),
+    /// Qualquer uma das condições
+    Any(Vec<TransitionCondition>),
+}
+
+/// Transição entre dois estados
+#[derive(Debug, Clone)]
+pub struct StateTransition {
+    /// Estado de origem (`None` = qualquer estado)
+    pub from: Option<String>,
+    /// Estado de destino
+    pub to: String,
+    /// Condição para a transição
+    pub condition: TransitionCondition,
+    /// Duração do cross-fade em segundos
+    pub duration: f32,
+}
+
+/// Eventos de interação recebidos pela entidade em um quadro
+#[derive(Debug, Clone, Copy, Default)]
+struct InteractionFlags {
+    selected: bool,
+    deselected: bool,
+    drag_started: bool,
+    drag_stopped: bool,
+}
+
+/// Emitido quando a máquina de estados troca de estado
+#[derive(Event, Debug, Clone)]
+pub struct AnimationStateChanged {
+    /// Entidade animada
+    pub entity: Entity,
+    /// Estado anterior
+    pub from: String,
+    /// Novo estado
+    pub to: String,
+}
+
+/// Componente que troca a animação da entidade de acordo com o estado
+///
+/// As transições são avaliadas na ordem em que foram adicionadas; no máximo
+/// uma acontece por quadro.
+#[derive(Component)]
+pub struct AnimationStateMachine {
+    states: HashMap<String, TimelineDefinition>,
+    transitions: Vec<StateTransition>,
+    current: String,
+    /// Se a timeline do estado atual já foi colocada na pilha
+    entered: bool,
+    /// Troca pedida por `go_to` (estado, duração)
+    pending: Option<(String, f32)>,
+    bools: HashMap<String, bool>,
+    floats: HashMap<String, f32>,
+    triggers: HashSet<String>,
+}
+
+impl AnimationStateMachine {
+    /// Cria uma máquina que começa no estado `initial`
+    pub fn new(initial: impl Into<String>) -> Self {
+        Self {
+            states: HashMap::new(),
+            transitions: Vec::new(),
+            current: initial.into(),
+            entered: false,
+            pending: None,
+            bools: HashMap::new(),
+            floats: HashMap::new(),
+            triggers: HashSet::new(),
+        }
+    }
+
+    /// Adiciona um estado com a sua timeline
+    ///
+    /// A timeline é guardada como definição e recriada a cada entrada no
+    /// estado; callbacks não são mantidos.
+    pub fn state(mut self, name: impl Into<String>, timeline: AnimationTimeline) -> Self {
+        self.states.insert(name.into(), timeline.to_definition());
+        self
+    }
+
+    /// Adiciona uma transição entre dois estados
+    pub fn transition(
+        mut self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        condition: TransitionCondition,
+        duration: f32,
+    ) -> Self {
+        self.transitions.push(StateTransition {
+            from: Some(from.into()),
+            to: to.into(),
+            condition,
+            duration,
+        });
+        self
+    }
+
+    /// Adiciona uma transição de qualquer outro estado para `to`
+    pub fn transition_from_any(
+        mut self,
+        to: impl Into<String>,
+        condition: TransitionCondition,
+        duration: f32,
+    ) -> Self {
+        self.transitions.push(StateTransition {
+            from: None,
+            to: to.into(),
+            condition,
+            duration,
+        });
+        self
+    }
+
+    /// Estado atual
+    pub fn current_state(&self) -> &str {
+        &self.current
+    }
+
+    /// Define um parâmetro booleano
+    pub fn set_bool(&mut self, name: impl Into<String>, value: bool) {
+        self.bools.insert(name.into(), value);
+    }
+
+    /// Lê um parâmetro booleano (`false` se ausente)
+    pub fn get_bool(&self, name: &str) -> bool {
+        self.bools.get(name).copied().unwrap_or(false)
+    }
+
+    /// Define um parâmetro numérico
+    pub fn set_float(&mut self, name: impl Into<String>, value: f32) {
+        self.floats.insert(name.into(), value);
+    }
+
+    /// Lê um parâmetro numérico
+    pub fn get_float(&self, name: &str) -> Option<f32> {
+        self.floats.get(name).copied()
+    }
+
+    /// Dispara um gatilho, consumido pela próxima transição que o usar
+    pub fn trigger(&mut self, name: impl Into<String>) {
+        self.triggers.insert(name.into());
+    }
+
+    /// Vai para um estado no próximo quadro, independente das transições
+    pub fn go_to(&mut self, state: impl Into<String>, duration: f32) {
+        self.pending = Some((state.into(), duration));
+    }
+
+    /// Verifica uma condição; gatilhos usados são adicionados a `used`
+    fn check(
+        &self,
+        condition: &TransitionCondition,
+        flags: InteractionFlags,
+        finished: bool,
+        used: &mut Vec<String>,
+    ) -> bool {
+        match condition {
+            TransitionCondition::Selected => flags.selected,
+            TransitionCondition::Deselected => flags.deselected,
+            TransitionCondition::DragStarted => flags.drag_started,
+            TransitionCondition::DragStopped => flags.drag_stopped,
+            TransitionCondition::Finished => finished,
+            TransitionCondition::Bool(name, value) => self.get_bool(name) == *value,
+            TransitionCondition::Greater(name, value) => self.get_float(name).is_some_and(|v| v > *value),
+            TransitionCondition::Less(name, value) => self.get_float(name).is_some_and(|v| v < *value),
+            TransitionCondition::Trigger(name) => {
+                let fired = self.triggers.contains(name);
+                if fired {
+                    used.push(name.clone());
+                }
+                fired
+            }
+            TransitionCondition::All(conditions) => {
+                let mut inner = Vec::new();
+                let passed = conditions.iter().all(|c| self.check(c, flags, finished, &mut inner));
+                if passed {
+                    used.extend(inner);
+                }
+                passed
+            }
+            TransitionCondition::Any(conditions) => {
+                conditions.iter().any(|c| self.check(c, flags, finished, used))
+            }
+        }
+    }
+
+    /// Escolhe a transição a ser feita neste quadro (estado, duração)
+    fn next_transition(&mut self, flags: InteractionFlags, finished: bool) -> Option<(String, f32)> {
+        if let Some(pending) = self.pending.take() {
+            return Some(pending);
+        }
+
+        let mut used = Vec::new();
+        let chosen = self.transitions.iter().find(|transition| {
+            let from_matches = match &transition.from {
+                Some(from) => *from == self.current,
+                None => transition.to != self.current,
+            };
+            used.clear();
+            from_matches && self.check(&transition.condition, flags, finished, &mut used)
+        });
+        let next = chosen.map(|transition| (transition.to.clone(), transition.duration));
+
+        for trigger in used {
+            self.triggers.remove(&trigger);
+        }
+        next
+    }
+
+    /// Cria a timeline de um estado
+    fn timeline_for(&self, state: &str) -> Option<AnimationTimeline> {
+        self.states.get(state).map(AnimationTimeline::from_definition)
+    }
+}
+
+/// Sistema que avalia as transições e troca a animação das entidades
+pub fn update_state_machines(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut AnimationStateMachine, Option<&mut AnimationStack>)>,
+    mut select_events: EventReader<SelectEvent>,
+    mut deselect_events: EventReader<DeselectEvent>,
+    mut drag_start_events: EventReader<StartDragEvent>,
+    mut drag_stop_events: EventReader<StopDragEvent>,
+    mut state_changed: EventWriter<AnimationStateChanged>,
+) {
+    let mut flags: HashMap<Entity, InteractionFlags> = HashMap::new();
+    for event in select_events.read() {
+        flags.entry(event.entity).or_default().selected = true;
+    }
+    for event in deselect_events.read() {
+        flags.entry(event.entity).or_default().deselected = true;
+    }
+    for event in drag_start_events.read() {
+        flags.entry(event.entity).or_default().drag_started = true;
+    }
+    for event in drag_stop_events.read() {
+        flags.entry(event.entity).or_default().drag_stopped = true;
+    }
+
+    for (entity, mut machine, stack) in query.iter_mut() {
+        let entity_flags = flags.get(&entity).copied().unwrap_or_default();
+        if entity_flags.selected || entity_flags.deselected {
+            machine.set_bool("selected", entity_flags.selected);
+        }
+        if entity_flags.drag_started || entity_flags.drag_stopped {
+            machine.set_bool("dragging", entity_flags.drag_started);
+        }
+
+        // Entrar no estado inicial
+        let Some(mut stack) = stack else {
+            let mut stack = AnimationStack::new();
+            if let Some(timeline) = machine.timeline_for(&machine.current) {
+                stack.push(AnimationLayer::new(STATE_LAYER, timeline));
+            }
+            machine.entered = true;
+            commands.entity(entity).insert(stack);
+            continue;
+        };
+        if !machine.entered {
+            if let Some(timeline) = machine.timeline_for(&machine.current) {
+                stack.push(AnimationLayer::new(STATE_LAYER, timeline));
+            }
+            machine.entered = true;
+        }
+
+        let finished = stack
+            .layer(STATE_LAYER)
+            .is_none_or(|layer| layer.timeline.is_finished());
+        let Some((next, duration)) = machine.next_transition(entity_flags, finished) else {
+            continue;
+        };
+
+        match machine.timeline_for(&next) {
+            Some(timeline) => stack.crossfade(STATE_LAYER, timeline, duration),
+            None => {
+                warn!("Estado de animação '{}' não existe", next);
+                continue;
+            }
+        }
+
+        let previous = std::mem::replace(&mut machine.current, next.clone());
+        state_changed.write(AnimationStateChanged {
+            entity,
+            from: previous,
+            to: next,
+        });
+    }
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 use super::keyframe::{Keyframe, KeyframeTrack, AnimatableProperty};
+use super::procedural::ProceduralTrack;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Estado de uma animação
@@ -16,9 +18,10 @@ pub enum AnimationState {
 }
 
 /// Modo de execução da animação
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub enum AnimationMode {
     /// Executar uma vez e parar
+    #[default]
     Once,
     /// Loop infinito
     Loop,
@@ -28,12 +31,31 @@ pub enum AnimationMode {
     Repeat(u32),
 }
 
+/// Evento gerado por uma timeline durante a reprodução
+///
+/// O `AnimationPlugin` converte estes eventos em eventos do Bevy
+/// (`AnimationStarted`, `AnimationCompleted`, `AnimationLooped` e
+/// `AnimationMarker`) com a entidade animada.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelineEvent {
+    /// A animação começou a ser reproduzida
+    Started,
+    /// A animação terminou
+    Completed,
+    /// A animação completou um ciclo e recomeçou (com o número de ciclos completos)
+    Looped(u32),
+    /// A reprodução passou por um marcador nomeado
+    Marker(String),
+}
+
 /// Timeline de animação que gerencia múltiplas tracks de propriedades
 pub struct AnimationTimeline {
     /// Nome da animação
     pub name: String,
     /// Tracks de propriedades (cada track anima uma propriedade diferente)
     pub tracks: HashMap<String, KeyframeTrack>,
+    /// Tracks procedurais, somadas aos valores das tracks de keyframes
+    pub procedural: Vec<ProceduralTrack>,
     /// Duração total da animação em segundos
     pub duration: f32,
     /// Modo de execução
@@ -42,7 +64,8 @@ pub struct AnimationTimeline {
     pub state: AnimationState,
     /// Tempo decorrido desde o início
     pub elapsed_time: f32,
-    /// Velocidade de reprodução (1.0 = normal, 2.0 = 2x mais rápido, 0.5 = metade da velocidade)
+    /// Velocidade de reprodução (1.0 = normal, 2.0 = 2x mais rápido, 0.5 = metade
+    /// da velocidade, valores negativos reproduzem de trás para frente)
     pub speed: f32,
     /// Contador de repetições (para modo Repeat)
     pub repeat_count: u32,
@@ -52,7 +75,17 @@ pub struct AnimationTimeline {
     pub delay: f32,
     /// Tempo de delay decorrido
     pub delay_elapsed: f32,
-    /// Callbacks opcionais
+    /// Se os valores iniciais já foram lidos da entidade
+    pub start_values_resolved: bool,
+    /// Se a timeline é controlada externamente (ex: por um scrubber) em vez de
+    /// avançar com o tempo; veja `set_driven_externally`
+    pub driven_externally: bool,
+    /// Marcadores nomeados (tempo, nome)
+    pub markers: Vec<(f32, String)>,
+    /// Eventos gerados e ainda não consumidos (veja `drain_events`)
+    pub pending_events: Vec<TimelineEvent>,
+    /// Callbacks opcionais (sem acesso ao mundo; com o `AnimationPlugin`,
+    /// prefira os eventos `AnimationStarted` e `AnimationCompleted`)
     pub on_complete: Option<Box<dyn Fn() + Send + Sync>>,
     pub on_start: Option<Box<dyn Fn() + Send + Sync>>,
 }
@@ -63,6 +96,7 @@ impl AnimationTimeline {
         Self {
             name,
             tracks: HashMap::new(),
+            procedural: Vec::new(),
             duration: 0.0,
             mode: AnimationMode::Once,
             state: AnimationState::Idle,
@@ -72,6 +106,10 @@ impl AnimationTimeline {
             reverse: false,
             delay: 0.0,
             delay_elapsed: 0.0,
+            start_values_resolved: false,
+            driven_externally: false,
+            markers: Vec::new(),
+            pending_events: Vec::new(),
             on_complete: None,
             on_start: None,
         }
@@ -90,19 +128,49 @@ impl AnimationTimeline {
     }
     
     /// Adiciona múltiplos keyframes de uma vez
-    pub fn add_keyframes(&mut self, track_name: &str, keyframes: Vec<Keyframe>) {
-        for keyframe in keyframes {
-            self.add_keyframe(track_name, keyframe);
-        }
+    ///
+    /// A track é ordenada e a duração recalculada uma única vez, então é a
+    /// forma mais rápida de montar tracks longas.
+    pub fn add_keyframes(&mut self, track_name: &str, keyframes: impl IntoIterator<Item = Keyframe>) {
+        self.tracks
+            .entry(track_name.to_string())
+            .or_insert_with(|| KeyframeTrack::new(track_name.to_string()))
+            .add_keyframes(keyframes);
+        
+        self.update_duration();
     }
     
     /// Atualiza a duração total baseada nas tracks
-    fn update_duration(&mut self) {
+    pub(super) fn update_duration(&mut self) {
         self.duration = self.tracks
             .values()
             .map(|track| track.duration())
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap_or(0.0);
+            .chain(self.markers.iter().map(|(time, _)| *time))
+            .chain(self.procedural.iter().filter_map(|track| track.end()))
+            // `f32::max` ignora tempos NaN vindos de arquivos
+            .fold(0.0, f32::max);
+    }
+    
+    /// Adiciona uma track procedural
+    ///
+    /// Tracks sem duração não aumentam a duração da timeline.
+    pub fn add_procedural(&mut self, track: ProceduralTrack) {
+        self.procedural.push(track);
+        self.update_duration();
+    }
+    
+    /// Adiciona um marcador nomeado, emitido quando a reprodução passa por `time`
+    pub fn add_marker(&mut self, time: f32, name: impl Into<String>) {
+        self.markers.push((time.max(0.0), name.into()));
+        self.markers.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.duration = self.duration.max(time);
+    }
+    
+    /// Retira os eventos gerados desde a última chamada
+    ///
+    /// Com o `AnimationPlugin` isso é feito automaticamente a cada frame.
+    pub fn drain_events(&mut self) -> Vec<TimelineEvent> {
+        std::mem::take(&mut self.pending_events)
     }
     
     /// Define o modo de execução
@@ -110,9 +178,18 @@ impl AnimationTimeline {
         self.mode = mode;
     }
     
-    /// Define a velocidade de reprodução
+    /// Define a velocidade de reprodução (negativa para reproduzir ao contrário)
     pub fn set_speed(&mut self, speed: f32) {
-        self.speed = speed.max(0.0);
+        self.speed = speed;
+    }
+    
+    /// Define se a timeline é controlada externamente
+    ///
+    /// Nesse modo `update` não avança o tempo; a posição muda apenas com
+    /// `seek`/`seek_progress` e os valores continuam sendo aplicados à entidade
+    /// pelo `AnimationPlugin`, mesmo com a animação pausada ou parada.
+    pub fn set_driven_externally(&mut self, driven: bool) {
+        self.driven_externally = driven;
     }
     
     /// Define o delay inicial
@@ -120,23 +197,57 @@ impl AnimationTimeline {
         self.delay = delay.max(0.0);
     }
     
+    /// Resolve os keyframes que dependem do estado da entidade
+    ///
+    /// `read_current` recebe a propriedade do primeiro keyframe de cada track e
+    /// retorna o valor atual dela na entidade. Chamado pelo `AnimationPlugin`
+    /// na primeira vez que a animação é reproduzida; os valores lidos são
+    /// mantidos em loops e reinícios.
+    pub fn resolve_start_values(
+        &mut self,
+        read_current: impl Fn(&AnimatableProperty) -> Option<AnimatableProperty>,
+    ) {
+        for track in self.tracks.values_mut() {
+            let current = track.keyframes.first().and_then(|k| read_current(&k.property));
+            track.resolve(current.as_ref());
+        }
+        for track in self.procedural.iter_mut() {
+            track.base = read_current(&track.amplitude);
+        }
+        self.start_values_resolved = true;
+    }
+    
+    /// Faz a timeline ler novamente os valores iniciais da entidade
+    /// na próxima reprodução
+    pub fn invalidate_start_values(&mut self) {
+        self.start_values_resolved = false;
+    }
+    
     /// Inicia a animação
+    ///
+    /// Com velocidade negativa a animação começa do fim.
     pub fn play(&mut self) {
         if self.state == AnimationState::Idle {
-            self.state = AnimationState::Playing;
-            self.elapsed_time = 0.0;
+            self.start(if self.speed < 0.0 { self.duration } else { 0.0 });
             self.delay_elapsed = 0.0;
-            self.repeat_count = 0;
-            self.reverse = false;
-            
-            if let Some(callback) = &self.on_start {
-                callback();
-            }
+            self.state = AnimationState::Playing;
         } else if self.state == AnimationState::Paused {
             self.state = AnimationState::Playing;
         }
     }
     
+    /// Reinicia o estado de reprodução a partir de `time` e emite `Started`
+    fn start(&mut self, time: f32) {
+        self.elapsed_time = time;
+        self.repeat_count = 0;
+        self.reverse = false;
+        self.pending_events.push(TimelineEvent::Started);
+        
+        if let Some(callback) = &self.on_start {
+            callback();
+        }
+    }
+    
     /// Pausa a animação
     pub fn pause(&mut self) {
         if self.state == AnimationState::Playing {
@@ -159,9 +270,40 @@ impl AnimationTimeline {
         self.play();
     }
     
+    /// Move a reprodução para um tempo da timeline (em segundos)
+    ///
+    /// Não emite marcadores. Uma animação parada é iniciada (emitindo
+    /// `Started`, sem o delay inicial) e, como uma finalizada, passa a ficar
+    /// pausada no novo tempo; `play` continua dali.
+    pub fn seek(&mut self, time: f32) {
+        let time = time.clamp(0.0, self.duration);
+        match self.state {
+            AnimationState::Idle => {
+                self.start(time);
+                self.delay_elapsed = self.delay;
+                self.state = AnimationState::Paused;
+            }
+            AnimationState::Finished => {
+                self.elapsed_time = time;
+                self.state = AnimationState::Paused;
+            }
+            _ => self.elapsed_time = time,
+        }
+    }
+    
+    /// Move a reprodução para uma fração da duração (0.0 a 1.0)
+    pub fn seek_progress(&mut self, progress: f32) {
+        self.seek(progress.clamp(0.0, 1.0) * self.duration);
+    }
+    
+    /// Verifica se os valores da timeline devem ser aplicados à entidade
+    pub fn is_active(&self) -> bool {
+        self.is_playing() || self.driven_externally
+    }
+    
     /// Atualiza a animação com o delta time
     pub fn update(&mut self, delta: f32) {
-        if self.state != AnimationState::Playing {
+        if self.state != AnimationState::Playing || self.driven_externally {
             return;
         }
         
@@ -182,6 +324,7 @@ impl AnimationTimeline {
     /// Atualiza a animação propriamente dita
     fn update_animation(&mut self, delta: f32) {
         let adjusted_delta = delta * self.speed;
+        let previous = self.elapsed_time;
         
         if self.reverse {
             self.elapsed_time -= adjusted_delta;
@@ -189,58 +332,256 @@ impl AnimationTimeline {
             self.elapsed_time += adjusted_delta;
         }
         
+        // Com velocidade negativa o ciclo começa no fim e termina no início
+        let backwards = self.speed < 0.0;
+        let (cycle_start, cycle_end) = if backwards {
+            (self.duration, 0.0)
+        } else {
+            (0.0, self.duration)
+        };
+        let past_end = if backwards {
+            self.elapsed_time <= 0.0
+        } else {
+            self.elapsed_time >= self.duration
+        };
+        
+        // Marcadores no ponto de partida só são emitidos no primeiro ciclo
+        let at_start = previous == cycle_start && self.repeat_count == 0;
+        if previous == self.elapsed_time {
+            return;
+        }
+        
+        // Sem duração não há ciclos a percorrer (e dividir pela duração daria NaN)
+        if self.duration <= 0.0 {
+            self.elapsed_time = 0.0;
+            if matches!(self.mode, AnimationMode::Once | AnimationMode::Repeat(_)) {
+                self.fire_markers(previous, 0.0, at_start);
+                self.complete();
+            }
+            return;
+        }
+        
         // Verificar limites e processar modos
         match self.mode {
             AnimationMode::Once => {
-                if self.elapsed_time >= self.duration {
-                    self.elapsed_time = self.duration;
-                    self.state = AnimationState::Finished;
-                    if let Some(callback) = &self.on_complete {
-                        callback();
-                    }
+                if past_end {
+                    self.elapsed_time = cycle_end;
+                    self.fire_markers(previous, cycle_end, at_start);
+                    self.complete();
+                } else {
+                    self.fire_markers(previous, self.elapsed_time, at_start);
                 }
             }
             AnimationMode::Loop => {
-                if self.elapsed_time >= self.duration {
-                    self.elapsed_time = self.elapsed_time % self.duration;
+                if past_end {
+                    self.wrap_cycles(previous, at_start, None);
+                } else {
+                    self.fire_markers(previous, self.elapsed_time, at_start);
                 }
             }
             AnimationMode::PingPong => {
-                if !self.reverse && self.elapsed_time >= self.duration {
-                    self.elapsed_time = self.duration;
-                    self.reverse = true;
-                } else if self.reverse && self.elapsed_time <= 0.0 {
-                    self.elapsed_time = 0.0;
-                    self.reverse = false;
-                    self.repeat_count += 1;
+                if self.elapsed_time >= self.duration || self.elapsed_time <= 0.0 {
+                    let bound = self.elapsed_time.clamp(0.0, self.duration);
+                    self.elapsed_time = bound;
+                    self.fire_markers(previous, bound, at_start);
+                    self.reverse = !self.reverse;
+                    // Um ciclo termina ao voltar ao ponto de partida
+                    if bound == cycle_start {
+                        self.repeat_count += 1;
+                        self.pending_events.push(TimelineEvent::Looped(self.repeat_count));
+                    }
+                } else {
+                    self.fire_markers(previous, self.elapsed_time, at_start);
                 }
             }
             AnimationMode::Repeat(count) => {
-                if self.elapsed_time >= self.duration {
-                    self.repeat_count += 1;
-                    if self.repeat_count >= count {
-                        self.elapsed_time = self.duration;
-                        self.state = AnimationState::Finished;
-                        if let Some(callback) = &self.on_complete {
-                            callback();
+                if past_end {
+                    self.wrap_cycles(previous, at_start, Some(count));
+                } else {
+                    self.fire_markers(previous, self.elapsed_time, at_start);
+                }
+            }
+        }
+        
+        self.elapsed_time = self.elapsed_time.clamp(0.0, self.duration);
+    }
+    
+    /// Processa os ciclos completados em um quadro (modos `Loop` e `Repeat`)
+    ///
+    /// Um delta maior que a duração percorre vários ciclos: cada um conta uma
+    /// repetição, emite `Looped` e os seus marcadores. Com `limit` a animação
+    /// termina no fim do ciclo que atinge o número de repetições.
+    fn wrap_cycles(&mut self, previous: f32, at_start: bool, limit: Option<u32>) {
+        let backwards = self.speed < 0.0;
+        let (cycle_start, cycle_end) = if backwards {
+            (self.duration, 0.0)
+        } else {
+            (0.0, self.duration)
+        };
+        // Distância percorrida desde o início do ciclo atual
+        let travelled = if backwards {
+            self.duration - self.elapsed_time
+        } else {
+            self.elapsed_time
+        };
+        let cycles = ((travelled / self.duration).floor() as u32).max(1);
+        let remainder = (travelled - cycles as f32 * self.duration).clamp(0.0, self.duration);
+        let position = if backwards { self.duration - remainder } else { remainder };
+        
+        self.fire_markers(previous, cycle_end, at_start);
+        for cycle in 1..=cycles {
+            self.repeat_count += 1;
+            if limit.is_some_and(|count| self.repeat_count >= count) {
+                self.elapsed_time = cycle_end;
+                self.complete();
+                return;
+            }
+            self.pending_events.push(TimelineEvent::Looped(self.repeat_count));
+            let to = if cycle < cycles { cycle_end } else { position };
+            self.fire_markers(cycle_start, to, true);
+        }
+        self.elapsed_time = position;
+    }
+    
+    /// Finaliza a animação e notifica a conclusão
+    fn complete(&mut self) {
+        self.state = AnimationState::Finished;
+        self.pending_events.push(TimelineEvent::Completed);
+        if let Some(callback) = &self.on_complete {
+            callback();
+        }
+    }
+    
+    /// Emite os marcadores cruzados ao ir de `from` para `to`
+    ///
+    /// O intervalo inclui `to` e só inclui `from` quando `include_from` é
+    /// verdadeiro (início da reprodução ou de um novo ciclo), para que cada
+    /// marcador seja emitido uma única vez por passagem.
+    fn fire_markers(&mut self, from: f32, to: f32, include_from: bool) {
+        if self.markers.is_empty() {
+            return;
+        }
+        
+        let (low, high) = (from.min(to), from.max(to));
+        let crossed = self.markers.iter().filter(|(time, _)| {
+            let inside = *time >= low && *time <= high;
+            inside && (*time != from || include_from)
+        });
+        // Os marcadores estão ordenados; para trás são emitidos em ordem inversa
+        let names: Vec<TimelineEvent> = if to >= from {
+            crossed.map(|(_, name)| TimelineEvent::Marker(name.clone())).collect()
+        } else {
+            crossed.rev().map(|(_, name)| TimelineEvent::Marker(name.clone())).collect()
+        };
+        self.pending_events.extend(names);
+    }
+    
+    /// Percorre os valores atuais de todas as propriedades animadas, sem alocar um mapa
+    ///
+    /// Cada valor já inclui as tracks procedurais; tracks de caminho produzem
+    /// a posição e, se o caminho girar a entidade, também a rotação.
+    pub fn for_each_current_value(&self, visit: impl FnMut(AnimatableProperty)) {
+        self.for_each_value_at(self.elapsed_time, visit);
+    }
+    
+    /// Percorre os valores de todas as propriedades animadas em um tempo da timeline
+    pub fn for_each_value_at(&self, time: f32, mut visit: impl FnMut(AnimatableProperty)) {
+        let mut emit = |value: AnimatableProperty| visit(self.with_procedural(value, time));
+        
+        for track in self.tracks.values() {
+            match track.get_value_at(time) {
+                Some(AnimatableProperty::PathProgress(progress)) => {
+                    if let Some((position, rotation)) = track.path_pose(progress) {
+                        emit(AnimatableProperty::Position(position));
+                        if let Some(rotation) = rotation {
+                            emit(AnimatableProperty::Rotation(rotation));
                         }
-                    } else {
-                        self.elapsed_time = self.elapsed_time % self.duration;
                     }
                 }
+                Some(value) => emit(value),
+                None => {}
             }
         }
         
-        self.elapsed_time = self.elapsed_time.clamp(0.0, self.duration);
+        // Tracks procedurais sem track de keyframes partem do valor inicial da entidade
+        for track in &self.procedural {
+            let Some(base) = &track.base else {
+                continue;
+            };
+            if self.tracks.values().any(|t| t.produces(&track.amplitude)) {
+                continue;
+            }
+            if let Some(offset) = track.offset_at(time) {
+                visit(base.with_vec4(base.to_vec4() + offset.to_vec4()));
+            }
+        }
+    }
+    
+    /// Verifica se a timeline produz valores da mesma propriedade que `property`
+    pub(crate) fn animates(&self, property: &AnimatableProperty) -> bool {
+        self.tracks.values().any(|track| track.produces(property))
+            || self
+                .procedural
+                .iter()
+                .any(|track| track.base.is_some() && track.amplitude.same_track(property))
+    }
+    
+    /// Soma a um valor as tracks procedurais da mesma propriedade
+    fn with_procedural(&self, mut value: AnimatableProperty, time: f32) -> AnimatableProperty {
+        for track in &self.procedural {
+            if !track.amplitude.same_track(&value) {
+                continue;
+            }
+            if let Some(offset) = track.offset_at(time) {
+                value = value.with_vec4(value.to_vec4() + offset.to_vec4());
+            }
+        }
+        value
     }
     
     /// Obtém os valores atuais de todas as propriedades animadas
     pub fn get_current_values(&self) -> HashMap<String, AnimatableProperty> {
+        self.values_at(self.elapsed_time)
+    }
+    
+    /// Obtém os valores de todas as propriedades animadas em um tempo da timeline
+    pub fn values_at(&self, time: f32) -> HashMap<String, AnimatableProperty> {
         let mut values = HashMap::new();
         
         for (name, track) in &self.tracks {
-            if let Some(value) = track.get_value_at(self.elapsed_time) {
-                values.insert(name.clone(), value);
+            match track.get_value_at(time) {
+                // Tracks de caminho produzem posição e, opcionalmente, rotação
+                Some(AnimatableProperty::PathProgress(progress)) => {
+                    if let Some((position, rotation)) = track.path_pose(progress) {
+                        values.insert(name.clone(), AnimatableProperty::Position(position));
+                        if let Some(rotation) = rotation {
+                            values.insert(format!("{name}.rotation"), AnimatableProperty::Rotation(rotation));
+                        }
+                    }
+                }
+                Some(value) => {
+                    values.insert(name.clone(), value);
+                }
+                None => {}
+            }
+        }
+        
+        // Somar as tracks procedurais ao valor da propriedade (ou ao valor inicial da entidade)
+        for track in &self.procedural {
+            let Some(offset) = track.offset_at(time) else {
+                continue;
+            };
+            let key = offset.track_name();
+            let existing = values.iter_mut().find(|(_, value)| value.track_name() == key);
+            match existing {
+                Some((_, value)) => {
+                    *value = value.with_vec4(value.to_vec4() + offset.to_vec4());
+                }
+                None => {
+                    if let Some(base) = &track.base {
+                        values.insert(key, base.with_vec4(base.to_vec4() + offset.to_vec4()));
+                    }
+                }
             }
         }
         
@@ -0,0 +1,83 @@
+//! Componentes para efeitos visuais das geometrias
+
+use bevy::prelude::*;
+
+/// Sombra projetada de uma geometria
+///
+/// A sombra é desenhada como camadas de mesh atrás da entidade, que seguem o
+/// seu `Transform` e a sua opacidade.
+#[derive(Component, Debug, Clone)]
+pub struct DropShadow {
+    /// Deslocamento da sombra em coordenadas do mundo
+    pub offset: Vec2,
+    /// Raio de desfoque em unidades do mundo (0.0 = sombra nítida)
+    pub blur: f32,
+    /// Cor da sombra (o alpha define a intensidade)
+    pub color: Color,
+}
+
+impl DropShadow {
+    /// Cria uma nova sombra
+    pub fn new(offset: Vec2, blur: f32, color: Color) -> Self {
+        Self {
+            offset,
+            blur: blur.max(0.0),
+            color,
+        }
+    }
+}
+
+impl Default for DropShadow {
+    fn default() -> Self {
+        Self::new(Vec2::new(6.0, -6.0), 8.0, Color::srgba(0.0, 0.0, 0.0, 0.5))
+    }
+}
+
+/// Brilho externo de uma geometria
+#[derive(Component, Debug, Clone)]
+pub struct Glow {
+    /// Distância que o brilho se estende além da borda, em unidades do mundo
+    pub radius: f32,
+    /// Cor do brilho (o alpha define a intensidade)
+    pub color: Color,
+}
+
+impl Glow {
+    /// Cria um novo brilho
+    pub fn new(radius: f32, color: Color) -> Self {
+        Self {
+            radius: radius.max(0.0),
+            color,
+        }
+    }
+}
+
+impl Default for Glow {
+    fn default() -> Self {
+        Self::new(12.0, Color::srgba(1.0, 1.0, 1.0, 0.6))
+    }
+}
+
+/// Tipo de efeito de uma camada gerada
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectKind {
+    Shadow,
+    Glow,
+}
+
+/// Camada de mesh gerada para um efeito (filha da entidade dona)
+#[derive(Component, Debug, Clone)]
+pub struct EffectLayer {
+    /// Entidade que possui o efeito
+    pub owner: Entity,
+    /// Tipo de efeito
+    pub kind: EffectKind,
+    /// Alpha da camada quando a entidade dona está totalmente opaca
+    pub base_alpha: f32,
+    /// Deslocamento da camada em coordenadas do mundo
+    pub offset: Vec2,
+    /// Quanto a camada se expande além da forma, em unidades do mundo
+    pub spread: f32,
+    /// Caixa delimitadora da mesh da entidade dona, em coordenadas locais
+    pub bounds: Rect,
+}
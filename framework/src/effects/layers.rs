@@ -0,0 +1,268 @@
+//! Geração e sincronização das camadas de sombra e brilho
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Mesh2d, VertexAttributeValues};
+use bevy::sprite::{AlphaMode2d, MeshMaterial2d};
+use super::components::{DropShadow, EffectKind, EffectLayer, Glow};
+use crate::geometrics::ShapeMaterial;
+
+/// Número de camadas usadas para aproximar o desfoque
+const BLUR_LAYERS: usize = 6;
+
+/// Profundidade das camadas de sombra em relação à entidade dona
+const SHADOW_DEPTH: f32 = -0.02;
+
+/// Profundidade das camadas de brilho em relação à entidade dona
+const GLOW_DEPTH: f32 = -0.01;
+
+/// Caixa usada quando a mesh da entidade dona não está disponível
+const DEFAULT_BOUNDS: Rect = Rect {
+    min: Vec2::splat(-50.0),
+    max: Vec2::splat(50.0),
+};
+
+/// Entidades donas de efeitos, com a transformação e o material de cor
+type EffectOwners<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+        Option<&'static MeshMaterial2d<ShapeMaterial>>,
+    ),
+    Without<EffectLayer>,
+>;
+
+/// Parâmetros de um efeito usados para gerar as suas camadas
+struct EffectParams {
+    kind: EffectKind,
+    /// Deslocamento em coordenadas do mundo
+    offset: Vec2,
+    /// Raio do desfoque (sombra) ou do brilho
+    spread: f32,
+    color: Color,
+}
+
+/// Recria as camadas de sombra quando o componente é adicionado ou alterado
+pub fn rebuild_shadow_layers(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    owners: Query<(Entity, &DropShadow, &Mesh2d), Changed<DropShadow>>,
+    layers: Query<(Entity, &EffectLayer)>,
+) {
+    for (owner, shadow, mesh) in owners.iter() {
+        despawn_layers(&mut commands, &layers, owner, EffectKind::Shadow);
+
+        let bounds = meshes.get(&mesh.0).map_or(DEFAULT_BOUNDS, mesh_bounds);
+
+        spawn_layers(
+            &mut commands,
+            &mut materials,
+            owner,
+            mesh,
+            bounds,
+            EffectParams {
+                kind: EffectKind::Shadow,
+                offset: shadow.offset,
+                spread: shadow.blur,
+                color: shadow.color,
+            },
+        );
+    }
+}
+
+/// Recria as camadas de brilho quando o componente é adicionado ou alterado
+pub fn rebuild_glow_layers(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    owners: Query<(Entity, &Glow, &Mesh2d), Changed<Glow>>,
+    layers: Query<(Entity, &EffectLayer)>,
+) {
+    for (owner, glow, mesh) in owners.iter() {
+        despawn_layers(&mut commands, &layers, owner, EffectKind::Glow);
+
+        if glow.radius <= 0.0 {
+            continue;
+        }
+
+        let bounds = meshes.get(&mesh.0).map_or(DEFAULT_BOUNDS, mesh_bounds);
+
+        spawn_layers(
+            &mut commands,
+            &mut materials,
+            owner,
+            mesh,
+            bounds,
+            EffectParams {
+                kind: EffectKind::Glow,
+                offset: Vec2::ZERO,
+                spread: glow.radius,
+                color: glow.color,
+            },
+        );
+    }
+}
+
+/// Remove as camadas quando o efeito é removido da entidade
+pub fn remove_effect_layers(
+    mut commands: Commands,
+    mut removed_shadows: RemovedComponents<DropShadow>,
+    mut removed_glows: RemovedComponents<Glow>,
+    layers: Query<(Entity, &EffectLayer)>,
+) {
+    for owner in removed_shadows.read() {
+        despawn_layers(&mut commands, &layers, owner, EffectKind::Shadow);
+    }
+    for owner in removed_glows.read() {
+        despawn_layers(&mut commands, &layers, owner, EffectKind::Glow);
+    }
+}
+
+/// Mantém o deslocamento e a expansão das camadas em coordenadas do mundo e
+/// aplica a opacidade atual da entidade dona (inclusive quando animada)
+pub fn sync_effect_layers(
+    owners: EffectOwners,
+    mut layers: Query<(&EffectLayer, &mut Transform, &MeshMaterial2d<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    shape_materials: Option<Res<Assets<ShapeMaterial>>>,
+) {
+    for (layer, mut transform, layer_material) in layers.iter_mut() {
+        let Ok((owner_transform, owner_material, owner_shape_material)) = owners.get(layer.owner) else {
+            continue;
+        };
+
+        // Compensar rotação e escala da entidade dona para manter offset e
+        // expansão no mundo
+        let local_offset = owner_transform.rotation.inverse() * layer.offset.extend(0.0);
+        let owner_scale = owner_transform.scale.truncate().max(Vec2::splat(f32::EPSILON));
+        let local_offset = local_offset.truncate() / owner_scale;
+        let (translation, scale) = layer_placement(layer.bounds, layer.spread / owner_scale, local_offset);
+        if transform.translation.truncate() != translation || transform.scale.truncate() != scale {
+            transform.translation = translation.extend(transform.translation.z);
+            transform.scale = scale.extend(1.0);
+        }
+
+        let owner_alpha = owner_material
+            .and_then(|m| materials.get(&m.0))
+            .map(|m| m.color.alpha())
+            .or_else(|| {
+                owner_shape_material
+                    .zip(shape_materials.as_ref())
+                    .and_then(|(m, assets)| assets.get(&m.0))
+                    .map(|m| m.color.alpha())
+            })
+            .unwrap_or(1.0);
+        let alpha = layer.base_alpha * owner_alpha;
+
+        let needs_update = materials
+            .get(&layer_material.0)
+            .is_some_and(|m| (m.color.alpha() - alpha).abs() > f32::EPSILON);
+        if needs_update {
+            if let Some(material) = materials.get_mut(&layer_material.0) {
+                material.color.set_alpha(alpha);
+            }
+        }
+    }
+}
+
+/// Cria as camadas de um efeito como filhas da entidade dona
+///
+/// O desfoque é aproximado por camadas progressivamente maiores; o alpha de
+/// cada camada é escolhido para que a sobreposição de todas resulte no alpha
+/// da cor do efeito.
+fn spawn_layers(
+    commands: &mut Commands,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    owner: Entity,
+    mesh: &Mesh2d,
+    bounds: Rect,
+    effect: EffectParams,
+) {
+    let EffectParams { kind, offset, spread, color } = effect;
+    let count = if spread > 0.0 { BLUR_LAYERS } else { 1 };
+    let target_alpha = color.alpha().clamp(0.0, 1.0);
+    let base_alpha = 1.0 - (1.0 - target_alpha).powf(1.0 / count as f32);
+    let depth = match kind {
+        EffectKind::Shadow => SHADOW_DEPTH,
+        EffectKind::Glow => GLOW_DEPTH,
+    };
+
+    let mut children = Vec::with_capacity(count);
+    for i in 0..count {
+        let radius = if count > 1 {
+            spread * i as f32 / (count - 1) as f32
+        } else {
+            0.0
+        };
+        // Escala inicial; `sync_effect_layers` compensa a escala da entidade dona
+        let (translation, scale) = layer_placement(bounds, Vec2::splat(radius), offset);
+
+        let mut material = ColorMaterial::from(color.with_alpha(base_alpha));
+        material.alpha_mode = AlphaMode2d::Blend;
+
+        let layer = commands
+            .spawn((
+                Mesh2d(mesh.0.clone()),
+                MeshMaterial2d(materials.add(material)),
+                Transform::from_translation(translation.extend(depth)).with_scale(scale.extend(1.0)),
+                EffectLayer {
+                    owner,
+                    kind,
+                    base_alpha,
+                    offset,
+                    spread: radius,
+                    bounds,
+                },
+            ))
+            .id();
+        children.push(layer);
+    }
+
+    commands.entity(owner).add_children(&children);
+}
+
+/// Despawna as camadas de um tipo de efeito pertencentes à entidade
+fn despawn_layers(
+    commands: &mut Commands,
+    layers: &Query<(Entity, &EffectLayer)>,
+    owner: Entity,
+    kind: EffectKind,
+) {
+    for (entity, layer) in layers.iter() {
+        if layer.owner == owner && layer.kind == kind {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Posição e escala locais de uma camada expandida `spread` unidades (locais)
+/// além da forma e deslocada por `offset`
+///
+/// A expansão é feita em torno do centro da caixa delimitadora, então formas
+/// cuja origem não fica no centro continuam alinhadas com a camada.
+fn layer_placement(bounds: Rect, spread: Vec2, offset: Vec2) -> (Vec2, Vec2) {
+    let half_extents = bounds.half_size().max(Vec2::splat(f32::EPSILON));
+    let scale = (half_extents + spread) / half_extents;
+    (offset + bounds.center() * (Vec2::ONE - scale), scale)
+}
+
+/// Calcula a caixa delimitadora de uma mesh 2D
+fn mesh_bounds(mesh: &Mesh) -> Rect {
+    let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return DEFAULT_BOUNDS;
+    };
+
+    let (min, max) = positions.iter().fold(
+        (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+        |(min, max), p| (min.min(Vec2::new(p[0], p[1])), max.max(Vec2::new(p[0], p[1]))),
+    );
+
+    if min.x > max.x {
+        DEFAULT_BOUNDS
+    } else {
+        Rect::from_corners(min, max)
+    }
+}
@@ -0,0 +1,59 @@
+//! Módulo de Efeitos Visuais
+//! 
+//! Fornece efeitos opcionais por geometria:
+//! - Drop Shadow: Sombra projetada com deslocamento, desfoque e cor
+//! - Glow: Brilho externo ao redor da forma
+//! 
+//! Os efeitos são gerados como camadas de mesh filhas da entidade, seguindo
+//! seu `Transform` e sua opacidade (inclusive quando animada).
+//! 
+//! # Exemplo de uso:
+//! ```rust
+//! use kosmos_framework::effects::prelude::*;
+//! 
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(EffectsPlugin)
+//!     .run();
+//! 
+//! // Adicionar sombra e brilho a uma geometria existente
+//! commands.entity(circle).insert((
+//!     DropShadow::new(Vec2::new(8.0, -8.0), 10.0, Color::srgba(0.0, 0.0, 0.0, 0.5)),
+//!     Glow::new(15.0, Color::srgba(0.3, 0.6, 1.0, 0.7)),
+//! ));
+//! ```
+
+use bevy::prelude::*;
+
+mod components;
+mod layers;
+
+// Re-exportar os tipos públicos
+pub use components::*;
+
+/// Plugin que gera e sincroniza as camadas de efeitos
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                layers::remove_effect_layers,
+                layers::rebuild_shadow_layers,
+                layers::rebuild_glow_layers,
+                layers::sync_effect_layers,
+            )
+                .chain()
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// Prelude para facilitar imports
+pub mod prelude {
+    pub use super::{
+        EffectsPlugin,
+        components::*,
+    };
+}
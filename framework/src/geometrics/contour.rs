@@ -0,0 +1,274 @@
+//! Utilitários de processamento de contornos
+//!
+//! Funções puras sobre contornos representados como `Vec<Vec2>`, úteis para
+//! morphing (mesmo número de vértices), importação de caminhos e operações
+//! booleanas. O parâmetro `closed` indica se o último ponto se conecta ao primeiro.
+
+use bevy::prelude::*;
+
+/// Comprimento total do contorno
+pub fn length(contour: &[Vec2], closed: bool) -> f32 {
+    segments(contour, closed).map(|(a, b)| a.distance(b)).sum()
+}
+
+/// Remove pontos consecutivos duplicados (distância menor ou igual a `tolerance`)
+///
+/// Em contornos fechados, o último ponto também é comparado com o primeiro.
+pub fn remove_duplicates(contour: &[Vec2], tolerance: f32, closed: bool) -> Vec<Vec2> {
+    let mut result: Vec<Vec2> = Vec::with_capacity(contour.len());
+
+    for &point in contour {
+        match result.last() {
+            Some(last) if last.distance(point) <= tolerance => {}
+            _ => result.push(point),
+        }
+    }
+
+    if closed {
+        while result.len() > 1 && result[0].distance(*result.last().unwrap()) <= tolerance {
+            result.pop();
+        }
+    }
+
+    result
+}
+
+/// Reamostra o contorno em `count` pontos igualmente espaçados ao longo do comprimento
+///
+/// Em contornos abertos, o primeiro e o último ponto são preservados.
+pub fn resample(contour: &[Vec2], count: usize, closed: bool) -> Vec<Vec2> {
+    if contour.is_empty() || count == 0 {
+        return Vec::new();
+    }
+
+    let total = length(contour, closed);
+    if contour.len() == 1 || total <= f32::EPSILON {
+        return vec![contour[0]; count];
+    }
+
+    // Contornos fechados dividem o perímetro em `count` partes; abertos em `count - 1`
+    let step = if closed {
+        total / count as f32
+    } else if count > 1 {
+        total / (count - 1) as f32
+    } else {
+        0.0
+    };
+
+    let mut result = Vec::with_capacity(count);
+    let mut segments = segments(contour, closed);
+    let (mut start, mut end) = segments.next().unwrap();
+    let mut segment_length = start.distance(end);
+    let mut travelled = 0.0;
+
+    for i in 0..count {
+        let target = step * i as f32;
+
+        // Avançar até o segmento que contém a distância alvo
+        while travelled + segment_length < target {
+            match segments.next() {
+                Some((a, b)) => {
+                    travelled += segment_length;
+                    start = a;
+                    end = b;
+                    segment_length = a.distance(b);
+                }
+                None => break,
+            }
+        }
+
+        let t = if segment_length > f32::EPSILON {
+            ((target - travelled) / segment_length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        result.push(start.lerp(end, t));
+    }
+
+    if !closed && count > 1 {
+        result[count - 1] = *contour.last().unwrap();
+    }
+
+    result
+}
+
+/// Simplifica o contorno com o algoritmo de Ramer–Douglas–Peucker
+///
+/// Pontos cuja distância à linha simplificada é menor que `epsilon` são removidos.
+pub fn simplify(contour: &[Vec2], epsilon: f32, closed: bool) -> Vec<Vec2> {
+    if contour.len() < 3 {
+        return contour.to_vec();
+    }
+
+    if !closed {
+        let mut keep = vec![false; contour.len()];
+        keep[0] = true;
+        keep[contour.len() - 1] = true;
+        rdp(contour, 0, contour.len() - 1, epsilon, &mut keep);
+        return filter_kept(contour, &keep);
+    }
+
+    // Em contornos fechados, dividir no ponto mais distante do primeiro para
+    // que os dois trechos tenham extremidades bem definidas
+    let far = (1..contour.len())
+        .max_by(|&a, &b| {
+            contour[0]
+                .distance_squared(contour[a])
+                .total_cmp(&contour[0].distance_squared(contour[b]))
+        })
+        .unwrap();
+
+    let mut ring = contour.to_vec();
+    ring.push(contour[0]);
+
+    let mut keep = vec![false; ring.len()];
+    keep[0] = true;
+    keep[far] = true;
+    rdp(&ring, 0, far, epsilon, &mut keep);
+    rdp(&ring, far, ring.len() - 1, epsilon, &mut keep);
+    keep.pop();
+    ring.pop();
+
+    filter_kept(&ring, &keep)
+}
+
+/// Suaviza o contorno com o algoritmo de corte de cantos de Chaikin
+///
+/// Cada iteração dobra o número de segmentos. Em contornos abertos, as
+/// extremidades são preservadas.
+pub fn smooth_chaikin(contour: &[Vec2], iterations: usize, closed: bool) -> Vec<Vec2> {
+    let mut current = contour.to_vec();
+
+    for _ in 0..iterations {
+        if current.len() < 3 {
+            break;
+        }
+
+        let mut next = Vec::with_capacity(current.len() * 2);
+        if !closed {
+            next.push(current[0]);
+        }
+
+        for (a, b) in segments(&current, closed) {
+            next.push(a.lerp(b, 0.25));
+            next.push(a.lerp(b, 0.75));
+        }
+
+        if !closed {
+            next.push(*current.last().unwrap());
+        }
+        current = next;
+    }
+
+    current
+}
+
+/// Suaviza o contorno com uma spline Catmull–Rom que passa por todos os pontos
+///
+/// `samples_per_segment` define quantos pontos são gerados entre cada par de
+/// pontos originais (1 mantém o contorno original).
+pub fn smooth_catmull_rom(contour: &[Vec2], samples_per_segment: usize, closed: bool) -> Vec<Vec2> {
+    let len = contour.len();
+    if len < 3 || samples_per_segment <= 1 {
+        return contour.to_vec();
+    }
+
+    let point = |i: isize| -> Vec2 {
+        if closed {
+            contour[i.rem_euclid(len as isize) as usize]
+        } else {
+            contour[i.clamp(0, len as isize - 1) as usize]
+        }
+    };
+
+    let segment_count = if closed { len } else { len - 1 };
+    let mut result = Vec::with_capacity(segment_count * samples_per_segment + 1);
+
+    for i in 0..segment_count as isize {
+        let p0 = point(i - 1);
+        let p1 = point(i);
+        let p2 = point(i + 1);
+        let p3 = point(i + 2);
+
+        for s in 0..samples_per_segment {
+            let t = s as f32 / samples_per_segment as f32;
+            result.push(catmull_rom(p0, p1, p2, p3, t));
+        }
+    }
+
+    if !closed {
+        result.push(contour[len - 1]);
+    }
+
+    result
+}
+
+/// Avalia um segmento Catmull–Rom uniforme entre `p1` e `p2`
+fn catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    0.5 * ((2.0 * p1)
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+/// Itera sobre os segmentos do contorno (incluindo o de fechamento, se `closed`)
+fn segments(contour: &[Vec2], closed: bool) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    let closing = if closed && contour.len() > 1 {
+        Some((*contour.last().unwrap(), contour[0]))
+    } else {
+        None
+    };
+
+    contour
+        .windows(2)
+        .map(|pair| (pair[0], pair[1]))
+        .chain(closing)
+}
+
+/// Passo recursivo do Ramer–Douglas–Peucker entre os índices `first` e `last`
+fn rdp(points: &[Vec2], first: usize, last: usize, epsilon: f32, keep: &mut [bool]) {
+    if last <= first + 1 {
+        return;
+    }
+
+    let mut max_distance = 0.0;
+    let mut index = first;
+
+    for i in first + 1..last {
+        let distance = distance_to_segment(points[i], points[first], points[last]);
+        if distance > max_distance {
+            max_distance = distance;
+            index = i;
+        }
+    }
+
+    if max_distance > epsilon {
+        keep[index] = true;
+        rdp(points, first, index, epsilon, keep);
+        rdp(points, index, last, epsilon, keep);
+    }
+}
+
+/// Distância de um ponto ao segmento `a`-`b`
+fn distance_to_segment(point: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared <= f32::EPSILON {
+        return point.distance(a);
+    }
+
+    let t = ((point - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    point.distance(a + ab * t)
+}
+
+fn filter_kept(points: &[Vec2], keep: &[bool]) -> Vec<Vec2> {
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, &k)| k)
+        .map(|(p, _)| *p)
+        .collect()
+}
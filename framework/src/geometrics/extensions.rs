@@ -1,5 +1,8 @@
 use bevy::prelude::*;
+use super::material::BlendMode;
 use super::shapes::Geometrics;
+use super::stroke::{StrokeOptions, StrokeTrim};
+use super::text::{GlyphFont, TextShapeOptions};
 
 /// Extensão trait para facilitar o uso com Commands
 pub trait GeometricsExt {
@@ -92,6 +95,30 @@ pub trait GeometricsExt {
         position: Vec2,
         filled: bool,
     ) -> Entity;
+
+    fn spawn_stroke(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        materials: &mut ResMut<Assets<ColorMaterial>>,
+        polyline: (Vec<Vec2>, bool),
+        options: StrokeOptions,
+        color: Color,
+        position: Vec2,
+    ) -> Entity;
+
+    fn spawn_text(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        materials: &mut ResMut<Assets<ColorMaterial>>,
+        font: &GlyphFont,
+        content: &str,
+        options: TextShapeOptions,
+        position: Vec2,
+    ) -> Entity;
+
+    fn set_blend_mode(&mut self, entity: Entity, blend_mode: BlendMode);
+
+    fn set_stroke_trim(&mut self, entity: Entity, trim: StrokeTrim);
 }
 
 impl GeometricsExt for Commands<'_, '_> {
@@ -202,4 +229,36 @@ impl GeometricsExt for Commands<'_, '_> {
     ) -> Entity {
         Geometrics::draw_heart(self, meshes, materials, size, color, position, filled)
     }
+
+    fn spawn_stroke(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        materials: &mut ResMut<Assets<ColorMaterial>>,
+        polyline: (Vec<Vec2>, bool),
+        options: StrokeOptions,
+        color: Color,
+        position: Vec2,
+    ) -> Entity {
+        Geometrics::stroke(self, meshes, materials, polyline, options, color, position)
+    }
+
+    fn spawn_text(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        materials: &mut ResMut<Assets<ColorMaterial>>,
+        font: &GlyphFont,
+        content: &str,
+        options: TextShapeOptions,
+        position: Vec2,
+    ) -> Entity {
+        Geometrics::text(self, meshes, materials, font, content, options, position)
+    }
+
+    fn set_blend_mode(&mut self, entity: Entity, blend_mode: BlendMode) {
+        Geometrics::set_blend_mode(self, entity, blend_mode)
+    }
+
+    fn set_stroke_trim(&mut self, entity: Entity, trim: StrokeTrim) {
+        Geometrics::set_stroke_trim(self, entity, trim)
+    }
 }
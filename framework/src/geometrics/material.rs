@@ -0,0 +1,248 @@
+//! Material 2D do Kosmos com controle de modo de blend por forma
+
+use bevy::asset::{load_internal_asset, weak_handle};
+use bevy::prelude::*;
+use bevy::render::mesh::MeshVertexBufferLayoutRef;
+use bevy::render::render_asset::RenderAssets;
+use bevy::render::render_resource::{
+    AsBindGroup, AsBindGroupShaderType, BlendComponent, BlendFactor, BlendOperation, BlendState,
+    RenderPipelineDescriptor, ShaderRef, SpecializedMeshPipelineError,
+};
+use bevy::render::texture::GpuImage;
+use bevy::sprite::{AlphaMode2d, Material2d, Material2dKey, Material2dPlugin, MeshMaterial2d};
+
+/// Handle do shader embutido do `ShapeMaterial`
+pub const SHAPE_MATERIAL_SHADER_HANDLE: Handle<Shader> =
+    weak_handle!("5b0c7a3e-2f4d-4b8e-9a61-3c1d7e5f9b24");
+
+/// Plugin que registra o `ShapeMaterial` e a troca de material por `BlendMode`
+pub struct ShapeMaterialPlugin;
+
+impl Plugin for ShapeMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            SHAPE_MATERIAL_SHADER_HANDLE,
+            "shape_material.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.add_plugins(Material2dPlugin::<ShapeMaterial>::default())
+            .add_systems(PostUpdate, apply_blend_modes);
+    }
+}
+
+/// Modo de blend usado para combinar a forma com o que está atrás dela
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendMode {
+    /// Transparência padrão (alpha blending)
+    #[default]
+    Alpha,
+    /// Soma a cor da forma ao fundo (brilhos, luzes)
+    Additive,
+    /// Multiplica a cor da forma pelo fundo (escurece)
+    Multiply,
+    /// Inverso da multiplicação (clareia)
+    Screen,
+}
+
+impl BlendMode {
+    /// Combina uma cor pré-multiplicada com o fundo, como o estado de blend do
+    /// pipeline faz na GPU (usado pelo renderizador em software)
+    pub(crate) fn blend(&self, src: Vec4, dst: Vec4) -> Vec4 {
+        let alpha = src.w + dst.w * (1.0 - src.w);
+        match self {
+            BlendMode::Alpha => src + dst * (1.0 - src.w),
+            BlendMode::Additive => (src.truncate() + dst.truncate()).extend(dst.w),
+            BlendMode::Multiply => {
+                (src.truncate() * dst.truncate() + dst.truncate() * (1.0 - src.w)).extend(alpha)
+            }
+            BlendMode::Screen => {
+                (src.truncate() + dst.truncate() * (Vec3::ONE - src.truncate())).extend(alpha)
+            }
+        }
+    }
+
+    /// Estado de blend do pipeline para uma cor de saída pré-multiplicada
+    fn blend_state(&self) -> BlendState {
+        let alpha_over = BlendComponent {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::OneMinusSrcAlpha,
+            operation: BlendOperation::Add,
+        };
+
+        match self {
+            BlendMode::Alpha => BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+            BlendMode::Additive => BlendState {
+                color: BlendComponent {
+                    src_factor: BlendFactor::One,
+                    dst_factor: BlendFactor::One,
+                    operation: BlendOperation::Add,
+                },
+                alpha: BlendComponent {
+                    src_factor: BlendFactor::Zero,
+                    dst_factor: BlendFactor::One,
+                    operation: BlendOperation::Add,
+                },
+            },
+            // resultado = src * dst + dst * (1 - src_alpha)
+            BlendMode::Multiply => BlendState {
+                color: BlendComponent {
+                    src_factor: BlendFactor::Dst,
+                    dst_factor: BlendFactor::OneMinusSrcAlpha,
+                    operation: BlendOperation::Add,
+                },
+                alpha: alpha_over,
+            },
+            // resultado = src + dst * (1 - src)
+            BlendMode::Screen => BlendState {
+                color: BlendComponent {
+                    src_factor: BlendFactor::One,
+                    dst_factor: BlendFactor::OneMinusSrc,
+                    operation: BlendOperation::Add,
+                },
+                alpha: alpha_over,
+            },
+        }
+    }
+}
+
+/// Material de cor sólida com modo de blend configurável
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+#[uniform(0, LinearRgba)]
+#[bind_group_data(ShapeMaterialKey)]
+pub struct ShapeMaterial {
+    /// Cor da forma
+    pub color: Color,
+    /// Modo de blend
+    pub blend_mode: BlendMode,
+    /// Se true, `color` já está pré-multiplicada pelo alpha
+    pub premultiplied: bool,
+}
+
+impl ShapeMaterial {
+    /// Cria um material com a cor especificada e blend alpha
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            blend_mode: BlendMode::Alpha,
+            premultiplied: false,
+        }
+    }
+
+    /// Define o modo de blend
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Indica que a cor já está pré-multiplicada pelo alpha
+    pub fn premultiplied(mut self) -> Self {
+        self.premultiplied = true;
+        self
+    }
+}
+
+impl Default for ShapeMaterial {
+    fn default() -> Self {
+        Self::new(Color::WHITE)
+    }
+}
+
+impl From<Color> for ShapeMaterial {
+    fn from(color: Color) -> Self {
+        Self::new(color)
+    }
+}
+
+impl AsBindGroupShaderType<LinearRgba> for ShapeMaterial {
+    fn as_bind_group_shader_type(&self, _images: &RenderAssets<GpuImage>) -> LinearRgba {
+        self.color.to_linear()
+    }
+}
+
+/// Chave de especialização do pipeline (um pipeline por combinação de opções)
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShapeMaterialKey {
+    blend_mode: BlendMode,
+    premultiplied: bool,
+}
+
+impl From<&ShapeMaterial> for ShapeMaterialKey {
+    fn from(material: &ShapeMaterial) -> Self {
+        Self {
+            blend_mode: material.blend_mode,
+            premultiplied: material.premultiplied,
+        }
+    }
+}
+
+impl Material2d for ShapeMaterial {
+    fn fragment_shader() -> ShaderRef {
+        SHAPE_MATERIAL_SHADER_HANDLE.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        // Todos os modos precisam da fase transparente (ordenada por profundidade)
+        AlphaMode2d::Blend
+    }
+
+    fn specialize(
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        if let Some(fragment) = descriptor.fragment.as_mut() {
+            if key.bind_group_data.premultiplied {
+                fragment.shader_defs.push("PREMULTIPLIED".into());
+            }
+            for target in fragment.targets.iter_mut().flatten() {
+                target.blend = Some(key.bind_group_data.blend_mode.blend_state());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formas cujo `BlendMode` mudou, com o material atual
+type BlendModeQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static BlendMode,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+        Option<&'static MeshMaterial2d<ShapeMaterial>>,
+    ),
+    Changed<BlendMode>,
+>;
+
+/// Aplica o `BlendMode` das entidades ao seu material
+///
+/// Formas com `ColorMaterial` passam a usar um `ShapeMaterial` com a mesma cor;
+/// formas que já usam `ShapeMaterial` apenas têm o modo atualizado.
+pub fn apply_blend_modes(
+    mut commands: Commands,
+    query: BlendModeQuery,
+    color_materials: Res<Assets<ColorMaterial>>,
+    mut shape_materials: ResMut<Assets<ShapeMaterial>>,
+) {
+    for (entity, blend_mode, color_material, shape_material) in query.iter() {
+        if let Some(shape_material) = shape_material {
+            if let Some(material) = shape_materials.get_mut(&shape_material.0) {
+                material.blend_mode = *blend_mode;
+            }
+        } else if let Some(color_material) = color_material {
+            let color = color_materials
+                .get(&color_material.0)
+                .map(|m| m.color)
+                .unwrap_or(Color::WHITE);
+
+            let material = ShapeMaterial::new(color).with_blend_mode(*blend_mode);
+            commands
+                .entity(entity)
+                .remove::<MeshMaterial2d<ColorMaterial>>()
+                .insert(MeshMaterial2d(shape_materials.add(material)));
+        }
+    }
+}
@@ -11,16 +11,47 @@
 //! 
 //! // Método 2: Usando a extensão trait (mais conciso)
 //! commands.spawn_circle(&mut meshes, &mut materials, Color::BLUE, 30.0, Vec2::new(100.0, 0.0));
+//!
+//! // Processamento de contornos (reamostragem, simplificação, suavização)
+//! let points = contour::resample(&coordinates, 60, true);
+//!
+//! // Texto como forma (contornos dos glifos)
+//! let font = GlyphFont::bundled();
+//! commands.spawn_text(&mut meshes, &mut materials, &font, "Kosmos", TextShapeOptions::new(48.0).with_color(Color::WHITE), Vec2::ZERO);
+//!
+//! // Linha com espessura, pontas arredondadas e cantos vivos
+//! let options = StrokeOptions::new(4.0).with_cap(StrokeCap::Round).with_join(StrokeJoin::Miter(4.0));
+//! let line = commands.spawn_stroke(&mut meshes, &mut materials, (points, false), options, Color::WHITE, Vec2::ZERO);
+//!
+//! // Desenhar a linha aos poucos (trim path animado)
+//! commands.set_stroke_trim(line, StrokeTrim::new(0.0, 0.0));
+//! commands.entity(line).insert(AnimationComponent::new(
+//!     AnimationBuilder::new("draw").animate_field("StrokeTrim::end", 1.0, 1.5, Easing::EaseInOut).build_and_play(),
+//! ));
+//!
+//! // Modo de blend por forma (requer `ShapeMaterialPlugin`)
+//! commands.set_blend_mode(circle, BlendMode::Additive);
 //! ```
 
 mod shapes;
 mod extensions;
+mod material;
+mod stroke;
+pub mod contour;
+pub mod text;
 
 // Re-exportar os tipos públicos
 pub use shapes::Geometrics;
 pub use extensions::GeometricsExt;
+pub use text::{GlyphFont, TextAlign, TextShapeOptions};
+pub use material::{BlendMode, ShapeMaterial, ShapeMaterialPlugin};
+pub use stroke::{update_stroke_trims, StrokeCap, StrokeGeometry, StrokeJoin, StrokeOptions, StrokeTrim, TrimMode};
 
 // Re-exportar um prelude para facilitar imports
 pub mod prelude {
-    pub use super::{Geometrics, GeometricsExt};
+    pub use super::{
+        Geometrics, GeometricsExt, GlyphFont, TextAlign, TextShapeOptions,
+        BlendMode, ShapeMaterial, ShapeMaterialPlugin,
+        StrokeCap, StrokeJoin, StrokeOptions, StrokeTrim, TrimMode,
+    };
 }
\ No newline at end of file
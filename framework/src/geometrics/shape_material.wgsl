@@ -0,0 +1,23 @@
+// Shader do ShapeMaterial: cor sólida com saída em alpha pré-multiplicado,
+// combinada no pipeline de acordo com o modo de blend do material
+
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+@group(2) @binding(0)
+var<uniform> color: vec4<f32>;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    var output_color = color;
+
+#ifdef VERTEX_COLORS
+    output_color = output_color * in.color;
+#endif
+
+    // Todos os modos de blend esperam a cor pré-multiplicada pelo alpha
+#ifndef PREMULTIPLIED
+    output_color = vec4<f32>(output_color.rgb * output_color.a, output_color.a);
+#endif
+
+    return output_color;
+}
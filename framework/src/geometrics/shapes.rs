@@ -2,10 +2,20 @@ use bevy::prelude::*;
 use bevy::render::mesh::{Indices, Mesh, Mesh2d, PrimitiveTopology};
 use bevy::render::render_asset::RenderAssetUsages;
 use bevy::sprite::MeshMaterial2d;
+use super::material::BlendMode;
+use super::stroke::{self, StrokeCap, StrokeGeometry, StrokeJoin, StrokeOptions, StrokeTrim};
+use super::text::{self, GlyphFont, TextShapeOptions};
 
 pub struct Geometrics;
 
 impl Geometrics {
+    /// Contorno usado por `draw` quando a forma não é preenchida
+    const OUTLINE: StrokeOptions = StrokeOptions {
+        width: 2.0,
+        cap: StrokeCap::Butt,
+        join: StrokeJoin::Miter(4.0),
+    };
+
     /// Cria um círculo 2D
     pub fn circle(
         commands: &mut Commands,
@@ -147,11 +157,12 @@ impl Geometrics {
             panic!("Uma forma precisa de pelo menos 3 pontos!");
         }
 
-        let mesh = if filled {
-            Self::create_filled_mesh(coordinates, scale)
-        } else {
-            Self::create_outline_mesh(coordinates, scale)
-        };
+        if !filled {
+            let points = coordinates.iter().map(|p| *p * scale).collect();
+            return Self::stroke(commands, meshes, materials, (points, true), Self::OUTLINE, color, position);
+        }
+
+        let mesh = Self::create_filled_mesh(coordinates, scale);
 
         commands
             .spawn((
@@ -162,6 +173,81 @@ impl Geometrics {
             .id()
     }
 
+    /// Desenha o contorno de uma polilinha com espessura, pontas e junções
+    ///
+    /// # Parâmetros
+    /// - `polyline`: Pontos da linha, relativos a `position`, e se o último
+    ///   ponto se conecta ao primeiro
+    /// - `options`: Espessura, pontas e junções
+    /// - `color`: Cor da linha
+    /// - `position`: Posição da forma no mundo
+    pub fn stroke(
+        commands: &mut Commands,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        materials: &mut ResMut<Assets<ColorMaterial>>,
+        polyline: (Vec<Vec2>, bool),
+        options: StrokeOptions,
+        color: Color,
+        position: Vec2,
+    ) -> Entity {
+        let polylines = vec![polyline];
+        let mesh = Self::create_stroke_mesh(&polylines, &options);
+
+        commands
+            .spawn((
+                Mesh2d(meshes.add(mesh)),
+                MeshMaterial2d(materials.add(ColorMaterial::from(color))),
+                Transform::from_translation(Vec3::new(position.x, position.y, 0.0)),
+                StrokeGeometry { polylines, options },
+            ))
+            .id()
+    }
+
+    /// Mostra só um trecho do contorno de uma forma (trim path)
+    ///
+    /// Funciona com `stroke` e com os contornos de `draw`; os campos do
+    /// `StrokeTrim` podem ser animados para desenhar o contorno aos poucos
+    /// (requer `AnimationPlugin`).
+    pub fn set_stroke_trim(commands: &mut Commands, entity: Entity, trim: StrokeTrim) {
+        commands.entity(entity).insert(trim);
+    }
+
+    /// Cria uma forma a partir do contorno dos glifos de um texto
+    ///
+    /// # Parâmetros
+    /// - `font`: Fonte TTF/OTF usada para gerar os contornos
+    /// - `content`: Texto a ser desenhado (aceita múltiplas linhas)
+    /// - `options`: Tamanho, alinhamento, espaçamento e cor do texto
+    /// - `position`: Posição do texto no mundo (ponto de alinhamento)
+    pub fn text(
+        commands: &mut Commands,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        materials: &mut ResMut<Assets<ColorMaterial>>,
+        font: &GlyphFont,
+        content: &str,
+        options: TextShapeOptions,
+        position: Vec2,
+    ) -> Entity {
+        let contours = text::text_contours(font, content, &options);
+        let mesh = Self::create_mesh_with_holes(text::group_contours(contours));
+
+        commands
+            .spawn((
+                Mesh2d(meshes.add(mesh)),
+                MeshMaterial2d(materials.add(ColorMaterial::from(options.color))),
+                Transform::from_translation(Vec3::new(position.x, position.y, 0.0)),
+            ))
+            .id()
+    }
+
+    /// Define o modo de blend de uma forma (requer `ShapeMaterialPlugin`)
+    ///
+    /// A forma passa a usar um `ShapeMaterial` com a mesma cor, mantendo a
+    /// compatibilidade com animações de cor e opacidade.
+    pub fn set_blend_mode(commands: &mut Commands, entity: Entity, blend_mode: BlendMode) {
+        commands.entity(entity).insert(blend_mode);
+    }
+
     /// Cria uma mesh preenchida a partir de coordenadas
     fn create_filled_mesh(coordinates: Vec<Vec2>, scale: f32) -> Mesh {
         let vertices: Vec<[f32; 3]> = coordinates
@@ -198,45 +284,34 @@ impl Geometrics {
         mesh
     }
 
-    /// Cria uma mesh de contorno a partir de coordenadas
-    fn create_outline_mesh(coordinates: Vec<Vec2>, scale: f32) -> Mesh {
-        let mut vertices = Vec::new();
-        let mut indices = Vec::new();
-        let line_width = 2.0; // Largura da linha
-
-        for i in 0..coordinates.len() {
-            let current = coordinates[i] * scale;
-            let next = coordinates[(i + 1) % coordinates.len()] * scale;
-
-            // Calcular direção perpendicular para criar espessura
-            let direction = (next - current).normalize();
-            let perpendicular = Vec2::new(-direction.y, direction.x) * line_width * 0.5;
-
-            // Adicionar 4 vértices para cada segmento
-            let base_index = (i * 4) as u32;
-
-            vertices.push([
-                current.x - perpendicular.x,
-                current.y - perpendicular.y,
-                0.0,
-            ]);
-            vertices.push([
-                current.x + perpendicular.x,
-                current.y + perpendicular.y,
-                0.0,
-            ]);
-            vertices.push([next.x + perpendicular.x, next.y + perpendicular.y, 0.0]);
-            vertices.push([next.x - perpendicular.x, next.y - perpendicular.y, 0.0]);
-
-            // Criar dois triângulos para formar um retângulo
-            indices.extend_from_slice(&[
-                base_index,
-                base_index + 1,
-                base_index + 2,
-                base_index,
-                base_index + 2,
-                base_index + 3,
-            ]);
+    /// Cria uma mesh preenchida a partir de polígonos (contorno externo + furos)
+    ///
+    /// Usa triangulação por ear clipping, suportando polígonos côncavos.
+    pub(crate) fn create_mesh_with_holes(polygons: Vec<(Vec<Vec2>, Vec<Vec<Vec2>>)>) -> Mesh {
+        let mut vertices: Vec<[f32; 3]> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+
+        for (outer, holes) in polygons {
+            let base_index = vertices.len();
+            let mut flat: Vec<f32> = Vec::new();
+            let mut hole_indices = Vec::new();
+
+            for point in &outer {
+                flat.extend_from_slice(&[point.x, point.y]);
+            }
+            for hole in &holes {
+                hole_indices.push(flat.len() / 2);
+                for point in hole {
+                    flat.extend_from_slice(&[point.x, point.y]);
+                }
+            }
+
+            let Ok(triangles) = earcutr::earcut(&flat, &hole_indices, 2) else {
+                continue;
+            };
+
+            vertices.extend(flat.chunks(2).map(|p| [p[0], p[1], 0.0]));
+            indices.extend(triangles.into_iter().map(|i| (base_index + i) as u32));
         }
 
         let mut mesh = Mesh::new(
@@ -244,19 +319,31 @@ impl Geometrics {
             RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
         );
 
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices.clone());
-        mesh.insert_indices(Indices::U32(indices));
+        // UVs normalizados pela caixa delimitadora
+        let (min, max) = vertices.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), v| (min.min(Vec2::new(v[0], v[1])), max.max(Vec2::new(v[0], v[1]))),
+        );
+        let extent = (max - min).max(Vec2::splat(f32::EPSILON));
+        let uvs: Vec<[f32; 2]> = vertices
+            .iter()
+            .map(|v| [(v[0] - min.x) / extent.x, (v[1] - min.y) / extent.y])
+            .collect();
 
-        // Adicionar normais e UVs
         let normals = vec![[0.0, 0.0, 1.0]; vertices.len()];
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+        mesh.insert_indices(Indices::U32(indices));
         mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-
-        let uvs = vec![[0.5, 0.5]; vertices.len()];
         mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
 
         mesh
     }
 
+    /// Cria a mesh do contorno de várias polilinhas (pontos, fechada)
+    pub(crate) fn create_stroke_mesh(polylines: &[(Vec<Vec2>, bool)], options: &StrokeOptions) -> Mesh {
+        stroke::stroke_mesh(polylines, options)
+    }
+
     /// Método auxiliar para criar uma estrela usando draw
     pub fn draw_star(
         commands: &mut Commands,
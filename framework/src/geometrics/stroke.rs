@@ -0,0 +1,404 @@
+//! Geração de malhas de contorno (stroke) com espessura, junções e pontas
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, Mesh2d, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+
+/// Número de segmentos usados em meia volta de junções e pontas arredondadas
+const ROUND_SEGMENTS: usize = 8;
+
+/// Forma das pontas de um contorno aberto
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StrokeCap {
+    /// Termina exatamente no ponto final
+    #[default]
+    Butt,
+    /// Meio círculo além do ponto final
+    Round,
+    /// Quadrado estendido por metade da espessura
+    Square,
+}
+
+/// Forma das junções entre segmentos
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum StrokeJoin {
+    /// Canto vivo, cortado quando o comprimento do canto passa do limite
+    /// (em múltiplos da espessura, como o `stroke-miterlimit` do SVG)
+    Miter(f32),
+    /// Canto arredondado
+    #[default]
+    Round,
+    /// Canto cortado
+    Bevel,
+}
+
+/// Opções de geração de um contorno
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeOptions {
+    /// Espessura da linha
+    pub width: f32,
+    /// Forma das pontas (só em contornos abertos)
+    pub cap: StrokeCap,
+    /// Forma das junções
+    pub join: StrokeJoin,
+}
+
+impl StrokeOptions {
+    /// Cria opções com a espessura dada, pontas retas e junções arredondadas
+    pub fn new(width: f32) -> Self {
+        Self {
+            width,
+            cap: StrokeCap::Butt,
+            join: StrokeJoin::Round,
+        }
+    }
+
+    /// Define a forma das pontas
+    pub fn with_cap(mut self, cap: StrokeCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Define a forma das junções
+    pub fn with_join(mut self, join: StrokeJoin) -> Self {
+        self.join = join;
+        self
+    }
+}
+
+impl Default for StrokeOptions {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+/// Como o trecho visível é medido quando o contorno tem vários caminhos
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrimMode {
+    /// Cada caminho mostra o mesmo trecho do seu próprio comprimento
+    #[default]
+    Simultaneous,
+    /// Os caminhos são percorridos em sequência, como um único caminho
+    Sequential,
+}
+
+/// Trecho visível de um contorno (trim path), em frações do comprimento
+///
+/// Anime os campos com `AnimatableProperty::Field` (`"StrokeTrim::end"`, ...)
+/// para desenhar ou apagar o contorno aos poucos. A mesh é refeita pelo
+/// `AnimationPlugin` sempre que o trecho muda; a entidade precisa ter um
+/// `StrokeGeometry` (criado por `Geometrics::stroke` e pelos contornos de
+/// `Geometrics::draw`).
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Default)]
+pub struct StrokeTrim {
+    /// Início do trecho (0.0 a 1.0)
+    pub start: f32,
+    /// Fim do trecho (0.0 a 1.0)
+    pub end: f32,
+    /// Deslocamento do trecho em voltas (1.0 = o comprimento inteiro)
+    pub offset: f32,
+    /// Medida do trecho quando há vários caminhos
+    pub mode: TrimMode,
+}
+
+impl StrokeTrim {
+    /// Mostra o trecho entre `start` e `end`
+    pub fn new(start: f32, end: f32) -> Self {
+        Self {
+            start,
+            end,
+            offset: 0.0,
+            mode: TrimMode::Simultaneous,
+        }
+    }
+
+    /// Define o deslocamento do trecho
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Define a medida do trecho quando há vários caminhos
+    pub fn with_mode(mut self, mode: TrimMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+impl Default for StrokeTrim {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+/// Caminhos e opções de um contorno, usados para refazer a mesh com trim
+#[derive(Component, Debug, Clone)]
+pub struct StrokeGeometry {
+    /// Polilinhas do contorno (pontos, fechada)
+    pub polylines: Vec<(Vec<Vec2>, bool)>,
+    /// Espessura, pontas e junções
+    pub options: StrokeOptions,
+}
+
+/// Contornos cujo trim ou geometria mudou (ou foi adicionado)
+type TrimChanged = Or<(Changed<StrokeTrim>, Changed<StrokeGeometry>)>;
+
+/// Sistema que refaz a mesh dos contornos cujo trecho visível mudou
+pub fn update_stroke_trims(
+    query: Query<(&StrokeTrim, &StrokeGeometry, &Mesh2d), TrimChanged>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+) {
+    let Some(mut meshes) = meshes else {
+        return;
+    };
+    for (trim, geometry, mesh) in &query {
+        if let Some(mesh) = meshes.get_mut(&mesh.0) {
+            *mesh = stroke_mesh(&trim_polylines(&geometry.polylines, trim), &geometry.options);
+        }
+    }
+}
+
+/// Recorta polilinhas no trecho visível de um trim
+///
+/// Os trechos recortados são abertos; um trecho que passa pelo ponto inicial
+/// de um caminho fechado continua sendo uma única linha.
+pub(crate) fn trim_polylines(polylines: &[(Vec<Vec2>, bool)], trim: &StrokeTrim) -> Vec<(Vec<Vec2>, bool)> {
+    let (start, end) = (trim.start.clamp(0.0, 1.0), trim.end.clamp(0.0, 1.0));
+    let (start, end) = (start.min(end), start.max(end));
+    if end - start >= 1.0 - 1e-6 {
+        return polylines.to_vec();
+    }
+    if end - start <= 1e-6 {
+        return Vec::new();
+    }
+
+    // Trechos visíveis em frações do comprimento, dando a volta no fim
+    let shift = trim.offset.rem_euclid(1.0);
+    let (from, to) = (start + shift, end + shift);
+    let ranges = if to <= 1.0 {
+        vec![(from, to)]
+    } else if from >= 1.0 {
+        vec![(from - 1.0, to - 1.0)]
+    } else {
+        vec![(from, 1.0), (0.0, to - 1.0)]
+    };
+
+    let measured: Vec<(Vec<(Vec2, f32)>, bool)> = polylines
+        .iter()
+        .map(|(points, closed)| (measure(points, *closed), *closed))
+        .collect();
+    let total: f32 = measured.iter().map(|(path, _)| path_length(path)).sum();
+
+    let mut result = Vec::new();
+    let mut before = 0.0;
+    for (path, closed) in &measured {
+        let length = path_length(path);
+        if length <= 0.0 {
+            continue;
+        }
+        let local: Vec<(f32, f32)> = match trim.mode {
+            TrimMode::Simultaneous => ranges.iter().map(|(a, b)| (a * length, b * length)).collect(),
+            TrimMode::Sequential => ranges
+                .iter()
+                .map(|(a, b)| ((a * total - before).max(0.0), (b * total - before).min(length)))
+                .filter(|(a, b)| b > a)
+                .collect(),
+        };
+        before += length;
+
+        let epsilon = length * 1e-5;
+        if *closed && local.len() == 2 && local[0].1 >= length - epsilon && local[1].0 <= epsilon {
+            let mut points = slice(path, local[0].0, length);
+            points.extend(slice(path, 0.0, local[1].1).into_iter().skip(1));
+            result.push((points, false));
+            continue;
+        }
+        result.extend(local.into_iter().map(|(a, b)| (slice(path, a, b), false)));
+    }
+    result
+}
+
+/// Pontos de uma polilinha com o comprimento acumulado até cada um
+fn measure(points: &[Vec2], closed: bool) -> Vec<(Vec2, f32)> {
+    let mut path = points.to_vec();
+    if closed && points.len() > 1 {
+        path.push(points[0]);
+    }
+
+    let mut length = 0.0;
+    let mut previous = path.first().copied().unwrap_or_default();
+    path.into_iter()
+        .map(|point| {
+            length += previous.distance(point);
+            previous = point;
+            (point, length)
+        })
+        .collect()
+}
+
+fn path_length(path: &[(Vec2, f32)]) -> f32 {
+    if path.len() < 2 {
+        return 0.0;
+    }
+    path.last().map_or(0.0, |(_, length)| *length)
+}
+
+/// Trecho de uma polilinha medida entre dois comprimentos
+fn slice(path: &[(Vec2, f32)], from: f32, to: f32) -> Vec<Vec2> {
+    let at = |distance: f32| {
+        let next = path.partition_point(|(_, length)| *length < distance).clamp(1, path.len() - 1);
+        let ((a, la), (b, lb)) = (path[next - 1], path[next]);
+        let span = lb - la;
+        if span > 0.0 {
+            a.lerp(b, ((distance - la) / span).clamp(0.0, 1.0))
+        } else {
+            b
+        }
+    };
+
+    let mut points = vec![at(from)];
+    points.extend(
+        path.iter()
+            .filter(|(_, length)| *length > from && *length < to)
+            .map(|(point, _)| *point),
+    );
+    points.push(at(to));
+    points
+}
+
+/// Triângulos do contorno de uma polilinha
+struct StrokeBuilder {
+    half_width: f32,
+    vertices: Vec<[f32; 3]>,
+    indices: Vec<u32>,
+}
+
+impl StrokeBuilder {
+    fn triangle(&mut self, a: Vec2, b: Vec2, c: Vec2) {
+        let base = self.vertices.len() as u32;
+        self.vertices.extend([a, b, c].map(|p| [p.x, p.y, 0.0]));
+        self.indices.extend([base, base + 1, base + 2]);
+    }
+
+    fn quad(&mut self, a: Vec2, b: Vec2, c: Vec2, d: Vec2) {
+        self.triangle(a, b, c);
+        self.triangle(a, c, d);
+    }
+
+    /// Leque de triângulos em volta de `center`, de `from` até `to` (offsets)
+    fn fan(&mut self, center: Vec2, from: Vec2, to: Vec2) {
+        self.arc(center, from, from.angle_to(to));
+    }
+
+    /// Leque de triângulos em volta de `center`, girando `from` por `angle`
+    fn arc(&mut self, center: Vec2, from: Vec2, angle: f32) {
+        let steps = ((angle.abs() / std::f32::consts::PI * ROUND_SEGMENTS as f32).ceil() as usize).max(1);
+        let mut previous = from;
+        for i in 1..=steps {
+            let next = Vec2::from_angle(angle * i as f32 / steps as f32).rotate(from);
+            self.triangle(center, center + previous, center + next);
+            previous = next;
+        }
+    }
+
+    /// Junção no vértice `point` entre segmentos de direção `d0` e `d1`
+    fn join(&mut self, point: Vec2, d0: Vec2, d1: Vec2, join: StrokeJoin) {
+        let turn = d0.perp_dot(d1);
+        if turn.abs() < 1e-6 && d0.dot(d1) > 0.0 {
+            return;
+        }
+        // O lado de fora da curva é o oposto ao sentido da virada
+        let side = if turn > 0.0 { -1.0 } else { 1.0 };
+        let from = d0.perp() * side * self.half_width;
+        let to = d1.perp() * side * self.half_width;
+
+        match join {
+            StrokeJoin::Round => self.fan(point, from, to),
+            StrokeJoin::Bevel => self.triangle(point, point + from, point + to),
+            StrokeJoin::Miter(limit) => {
+                let bisector = (from + to).normalize_or_zero();
+                let cos = bisector.dot(from.normalize_or_zero());
+                if cos > f32::EPSILON && 1.0 / cos <= limit {
+                    let tip = point + bisector * self.half_width / cos;
+                    self.quad(point, point + from, tip, point + to);
+                } else {
+                    self.triangle(point, point + from, point + to);
+                }
+            }
+        }
+    }
+
+    fn polyline(&mut self, points: &[Vec2], closed: bool, options: &StrokeOptions) {
+        let mut points = points.to_vec();
+        points.dedup_by(|a, b| a.distance_squared(*b) < 1e-12);
+        if closed && points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        if points.len() < 2 {
+            return;
+        }
+
+        let segment_count = if closed { points.len() } else { points.len() - 1 };
+        let segment = |i: usize| (points[i], points[(i + 1) % points.len()]);
+        let direction = |i: usize| {
+            let (a, b) = segment(i);
+            (b - a).normalize_or_zero()
+        };
+
+        for i in 0..segment_count {
+            let (mut a, mut b) = segment(i);
+            let d = direction(i);
+            if !closed && options.cap == StrokeCap::Square {
+                if i == 0 {
+                    a -= d * self.half_width;
+                }
+                if i == segment_count - 1 {
+                    b += d * self.half_width;
+                }
+            }
+            let n = d.perp() * self.half_width;
+            self.quad(a + n, a - n, b - n, b + n);
+        }
+
+        let joints = if closed { 0..segment_count } else { 1..segment_count };
+        for i in joints {
+            let previous = (i + segment_count - 1) % segment_count;
+            self.join(points[i], direction(previous), direction(i), options.join);
+        }
+
+        if !closed && options.cap == StrokeCap::Round {
+            let start = direction(0).perp() * self.half_width;
+            let end = direction(segment_count - 1).perp() * self.half_width;
+            // Meias voltas no sentido anti-horário, para fora da linha
+            self.arc(points[0], start, std::f32::consts::PI);
+            self.arc(points[segment_count], -end, std::f32::consts::PI);
+        }
+    }
+}
+
+/// Cria a mesh do contorno de várias polilinhas (pontos, fechada)
+pub(crate) fn stroke_mesh(polylines: &[(Vec<Vec2>, bool)], options: &StrokeOptions) -> Mesh {
+    let mut builder = StrokeBuilder {
+        half_width: options.width.max(0.0) * 0.5,
+        vertices: Vec::new(),
+        indices: Vec::new(),
+    };
+    for (points, closed) in polylines {
+        builder.polyline(points, *closed, options);
+    }
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    );
+
+    let normals = vec![[0.0, 0.0, 1.0]; builder.vertices.len()];
+    let uvs = vec![[0.5, 0.5]; builder.vertices.len()];
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, builder.vertices);
+    mesh.insert_indices(Indices::U32(builder.indices));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+
+    mesh
+}
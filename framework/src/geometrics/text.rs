@@ -0,0 +1,343 @@
+//! Texto como forma geométrica
+//!
+//! Converte os contornos dos glifos de uma fonte TTF/OTF em contornos `Vec2`,
+//! que são triangulados (com furos) como qualquer outra forma de `Geometrics`.
+
+use bevy::prelude::*;
+use std::sync::Arc;
+use ttf_parser::{Face, FaceParsingError, OutlineBuilder};
+use super::contour;
+
+/// Fonte embutida no framework (Fira Mono, subconjunto ASCII)
+const BUNDLED_FONT: &[u8] = include_bytes!("../../../assets/fonts/FiraMono-subset.ttf");
+
+/// Número de segmentos usados para aproximar cada curva de Bézier do glifo
+const CURVE_SEGMENTS: usize = 8;
+
+/// Fonte usada para gerar formas de texto
+#[derive(Clone)]
+pub struct GlyphFont {
+    data: Arc<Vec<u8>>,
+    index: u32,
+}
+
+impl GlyphFont {
+    /// Carrega uma fonte TTF/OTF a partir dos bytes do arquivo
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, FaceParsingError> {
+        Self::from_bytes_with_index(data, 0)
+    }
+
+    /// Carrega uma face específica de uma coleção de fontes (TTC/OTC)
+    pub fn from_bytes_with_index(data: Vec<u8>, index: u32) -> Result<Self, FaceParsingError> {
+        Face::parse(&data, index)?;
+        Ok(Self {
+            data: Arc::new(data),
+            index,
+        })
+    }
+
+    /// Fonte embutida no framework
+    pub fn bundled() -> Self {
+        Self::from_bytes(BUNDLED_FONT.to_vec()).expect("Fonte embutida inválida")
+    }
+
+    fn face(&self) -> Face<'_> {
+        // A fonte já foi validada na criação
+        Face::parse(&self.data, self.index).unwrap()
+    }
+}
+
+impl Default for GlyphFont {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+/// Alinhamento horizontal das linhas de texto em relação à posição
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    /// O texto começa na posição
+    Left,
+    /// O texto é centralizado na posição
+    #[default]
+    Center,
+    /// O texto termina na posição
+    Right,
+}
+
+/// Opções de layout e aparência para formas de texto
+#[derive(Debug, Clone, Copy)]
+pub struct TextShapeOptions {
+    /// Tamanho da fonte em unidades do mundo (altura do em)
+    pub size: f32,
+    /// Alinhamento horizontal
+    pub align: TextAlign,
+    /// Espaço extra entre letras, em unidades do mundo
+    pub letter_spacing: f32,
+    /// Multiplicador da altura de linha da fonte
+    pub line_height: f32,
+    /// Cor do texto
+    pub color: Color,
+}
+
+impl Default for TextShapeOptions {
+    fn default() -> Self {
+        Self {
+            size: 32.0,
+            align: TextAlign::Center,
+            letter_spacing: 0.0,
+            line_height: 1.0,
+            color: Color::WHITE,
+        }
+    }
+}
+
+impl TextShapeOptions {
+    /// Cria opções com o tamanho especificado
+    pub fn new(size: f32) -> Self {
+        Self {
+            size,
+            ..Default::default()
+        }
+    }
+
+    /// Define o alinhamento horizontal
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Define o espaçamento entre letras
+    pub fn with_letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Define o multiplicador da altura de linha
+    pub fn with_line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Define a cor do texto
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// Gera os contornos fechados de um texto, centralizados verticalmente na origem
+///
+/// Os contornos externos e os furos são retornados juntos; use
+/// [`group_contours`] para separá-los.
+pub fn text_contours(font: &GlyphFont, text: &str, options: &TextShapeOptions) -> Vec<Vec<Vec2>> {
+    let face = font.face();
+    let scale = options.size / face.units_per_em() as f32;
+    let line_advance = (font_height(&face) + face.line_gap() as f32) * scale * options.line_height;
+
+    let lines: Vec<&str> = text.lines().collect();
+    let block_height = line_advance * lines.len().saturating_sub(1) as f32
+        + font_height(&face) * scale;
+    // Baseline da primeira linha para que o bloco fique centralizado em y = 0
+    let first_baseline = block_height / 2.0 - face.ascender() as f32 * scale;
+
+    let mut contours = Vec::new();
+
+    for (line_index, line) in lines.iter().enumerate() {
+        let baseline = first_baseline - line_advance * line_index as f32;
+        let width = line_width(&face, line, scale, options.letter_spacing);
+        let start_x = match options.align {
+            TextAlign::Left => 0.0,
+            TextAlign::Center => -width / 2.0,
+            TextAlign::Right => -width,
+        };
+
+        let mut pen_x = start_x;
+        for character in line.chars() {
+            let Some(glyph) = face.glyph_index(character) else {
+                continue;
+            };
+
+            let mut builder = GlyphOutline {
+                offset: Vec2::new(pen_x, baseline),
+                scale,
+                contours: Vec::new(),
+                current: Vec::new(),
+            };
+            face.outline_glyph(glyph, &mut builder);
+            builder.finish();
+            contours.extend(builder.contours);
+
+            pen_x += face.glyph_hor_advance(glyph).unwrap_or(0) as f32 * scale
+                + options.letter_spacing;
+        }
+    }
+
+    contours
+}
+
+/// Calcula o tamanho da caixa delimitadora de um texto (útil para `GeometryBounds`)
+pub fn text_size(font: &GlyphFont, text: &str, options: &TextShapeOptions) -> Vec2 {
+    let face = font.face();
+    let scale = options.size / face.units_per_em() as f32;
+    let line_advance = (font_height(&face) + face.line_gap() as f32) * scale * options.line_height;
+
+    let lines: Vec<&str> = text.lines().collect();
+    let width = lines
+        .iter()
+        .map(|line| line_width(&face, line, scale, options.letter_spacing))
+        .fold(0.0, f32::max);
+    let height = line_advance * lines.len().saturating_sub(1) as f32
+        + font_height(&face) * scale;
+
+    Vec2::new(width, height)
+}
+
+/// Agrupa contornos em polígonos (contorno externo + furos) pela profundidade de aninhamento
+///
+/// Contornos com profundidade par são externos; os de profundidade ímpar são
+/// furos do contorno externo mais interno que os contém. Funciona para fontes
+/// TrueType e CFF independentemente do sentido dos contornos.
+pub fn group_contours(contours: Vec<Vec<Vec2>>) -> Vec<(Vec<Vec2>, Vec<Vec<Vec2>>)> {
+    let contours: Vec<Vec<Vec2>> = contours.into_iter().filter(|c| c.len() >= 3).collect();
+
+    // Para cada contorno, os índices dos contornos que o contêm
+    let containers: Vec<Vec<usize>> = contours
+        .iter()
+        .enumerate()
+        .map(|(i, inner)| {
+            (0..contours.len())
+                .filter(|&j| j != i && point_in_polygon(inner[0], &contours[j]))
+                .collect()
+        })
+        .collect();
+
+    let mut polygons: Vec<(usize, Vec<Vec<Vec2>>)> = Vec::new();
+    let mut outer_slot = vec![None; contours.len()];
+
+    for (i, parents) in containers.iter().enumerate() {
+        if parents.len() % 2 == 0 {
+            outer_slot[i] = Some(polygons.len());
+            polygons.push((i, Vec::new()));
+        }
+    }
+
+    for (i, parents) in containers.iter().enumerate() {
+        if parents.len() % 2 == 1 {
+            // O pai direto é o contêiner com maior profundidade
+            let parent = parents
+                .iter()
+                .copied()
+                .max_by_key(|&p| containers[p].len())
+                .unwrap();
+            if let Some(slot) = outer_slot[parent] {
+                polygons[slot].1.push(contours[i].clone());
+            }
+        }
+    }
+
+    polygons
+        .into_iter()
+        .map(|(outer, holes)| (contours[outer].clone(), holes))
+        .collect()
+}
+
+/// Altura de uma linha sem espaçamento, em unidades da fonte
+///
+/// Calculada em `f32`: métricas grandes estouram `i16`.
+fn font_height(face: &Face) -> f32 {
+    face.ascender() as f32 - face.descender() as f32
+}
+
+fn line_width(face: &Face, line: &str, scale: f32, letter_spacing: f32) -> f32 {
+    let glyphs: Vec<_> = line.chars().filter_map(|c| face.glyph_index(c)).collect();
+    let advance: f32 = glyphs
+        .iter()
+        .map(|&g| face.glyph_hor_advance(g).unwrap_or(0) as f32 * scale)
+        .sum();
+    advance + letter_spacing * glyphs.len().saturating_sub(1) as f32
+}
+
+/// Teste par-ímpar de ponto dentro de polígono
+fn point_in_polygon(point: Vec2, polygon: &[Vec2]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[j]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
+
+/// Coleta os contornos de um glifo, achatando as curvas em segmentos
+struct GlyphOutline {
+    offset: Vec2,
+    scale: f32,
+    contours: Vec<Vec<Vec2>>,
+    current: Vec<Vec2>,
+}
+
+impl GlyphOutline {
+    fn point(&self, x: f32, y: f32) -> Vec2 {
+        self.offset + Vec2::new(x, y) * self.scale
+    }
+
+    fn finish(&mut self) {
+        let points = contour::remove_duplicates(&self.current, 1e-4, true);
+        if points.len() >= 3 {
+            self.contours.push(points);
+        }
+        self.current.clear();
+    }
+}
+
+impl OutlineBuilder for GlyphOutline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish();
+        let p = self.point(x, y);
+        self.current.push(p);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let p = self.point(x, y);
+        self.current.push(p);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = *self.current.last().unwrap_or(&Vec2::ZERO);
+        let p1 = self.point(x1, y1);
+        let p2 = self.point(x, y);
+
+        for i in 1..=CURVE_SEGMENTS {
+            let t = i as f32 / CURVE_SEGMENTS as f32;
+            let mt = 1.0 - t;
+            self.current.push(p0 * mt * mt + p1 * 2.0 * mt * t + p2 * t * t);
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = *self.current.last().unwrap_or(&Vec2::ZERO);
+        let p1 = self.point(x1, y1);
+        let p2 = self.point(x2, y2);
+        let p3 = self.point(x, y);
+
+        for i in 1..=CURVE_SEGMENTS {
+            let t = i as f32 / CURVE_SEGMENTS as f32;
+            let mt = 1.0 - t;
+            self.current.push(
+                p0 * mt * mt * mt + p1 * 3.0 * mt * mt * t + p2 * 3.0 * mt * t * t + p3 * t * t * t,
+            );
+        }
+    }
+
+    fn close(&mut self) {
+        self.finish();
+    }
+}
@@ -7,6 +7,7 @@ use super::{
     input_utils::{self, MouseWorldPosition},
     InteractionMode,
 };
+use crate::layers::{Layer, LayerStack, ZOrder};
 
 /// Plugin para funcionalidade de arrastar e soltar
 pub struct DragPlugin;
@@ -36,7 +37,11 @@ fn not_in_transform_mode(mode: Res<InteractionMode>) -> bool {
 fn detect_drag_start(
     mouse_button: Res<ButtonInput<MouseButton>>,
     mouse_pos: Res<MouseWorldPosition>,
-    draggable_query: Query<(Entity, &Transform, Option<&GeometryBounds>), (With<Draggable>, Without<Dragging>)>,
+    draggable_query: Query<
+        (Entity, &Transform, Option<&GeometryBounds>, Option<&Layer>, Option<&ZOrder>),
+        (With<Draggable>, Without<Dragging>),
+    >,
+    layer_stack: Res<LayerStack>,
     mut drag_start_events: EventWriter<StartDragEvent>,
     mut interaction_mode: ResMut<InteractionMode>,
 ) {
@@ -49,31 +54,7 @@ fn detect_drag_start(
     }
     
     // Encontrar entidade sob o cursor
-    let mut closest_entity = None;
-    let mut closest_z = f32::MIN;
-    
-    for (entity, transform, bounds) in draggable_query.iter() {
-        let is_hit = if let Some(bounds) = bounds {
-            input_utils::point_in_bounds(
-                mouse_pos.0,
-                transform.translation,
-                bounds.size,
-                transform.scale,
-            )
-        } else {
-            input_utils::point_in_bounds(
-                mouse_pos.0,
-                transform.translation,
-                Vec2::new(100.0, 100.0),
-                transform.scale,
-            )
-        };
-        
-        if is_hit && transform.translation.z > closest_z {
-            closest_z = transform.translation.z;
-            closest_entity = Some(entity);
-        }
-    }
+    let closest_entity = input_utils::find_entity_under_cursor(mouse_pos.0, &layer_stack, draggable_query.iter());
     
     if let Some(entity) = closest_entity {
         drag_start_events.send(StartDragEvent {
@@ -2,6 +2,8 @@
 
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
+use super::components::GeometryBounds;
+use crate::layers::{draw_order, Layer, LayerStack, ZOrder};
 
 /// Recurso que armazena a posição do mouse em coordenadas do mundo
 #[derive(Resource, Debug, Clone, Default)]
@@ -58,39 +60,31 @@ pub fn point_in_circle(
     distance <= radius * scale
 }
 
-/// Encontra a entidade mais próxima sob o cursor
-pub fn find_entity_under_cursor(
+/// Encontra a entidade mais à frente sob o cursor
+///
+/// A ordem de desenho vem das camadas (`draw_order`), não de `translation.z` bruto.
+pub fn find_entity_under_cursor<'a>(
     cursor_pos: Vec2,
-    query: &Query<(Entity, &Transform, Option<&super::components::GeometryBounds>)>,
+    stack: &LayerStack,
+    candidates: impl IntoIterator<
+        Item = (
+            Entity,
+            &'a Transform,
+            Option<&'a GeometryBounds>,
+            Option<&'a Layer>,
+            Option<&'a ZOrder>,
+        ),
+    >,
 ) -> Option<Entity> {
-    let mut closest_entity = None;
-    let mut closest_z = f32::MIN;
-    
-    for (entity, transform, bounds) in query.iter() {
-        let is_hit = if let Some(bounds) = bounds {
-            point_in_bounds(
-                cursor_pos,
-                transform.translation,
-                bounds.size,
-                transform.scale,
-            )
-        } else {
+    candidates
+        .into_iter()
+        .filter(|(_, transform, bounds, _, _)| {
             // Usar um tamanho padrão se não houver bounds definidos
-            point_in_bounds(
-                cursor_pos,
-                transform.translation,
-                Vec2::new(100.0, 100.0),
-                transform.scale,
-            )
-        };
-        
-        if is_hit && transform.translation.z > closest_z {
-            closest_z = transform.translation.z;
-            closest_entity = Some(entity);
-        }
-    }
-    
-    closest_entity
+            let size = bounds.map(|b| b.size).unwrap_or(Vec2::new(100.0, 100.0));
+            point_in_bounds(cursor_pos, transform.translation, size, transform.scale)
+        })
+        .max_by_key(|(_, transform, _, layer, order)| draw_order(stack, *layer, *order, transform))
+        .map(|(entity, ..)| entity)
 }
 
 /// Calcula a distância e ângulo entre dois pontos
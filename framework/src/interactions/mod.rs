@@ -16,6 +16,7 @@
 //! ```
 
 use bevy::prelude::*;
+use crate::layers::LayersPlugin;
 
 mod components;
 mod events;
@@ -59,6 +60,11 @@ impl Plugin for InteractionsPlugin {
                 TransformPlugin,
                 SelectPlugin,
             ));
+        
+        // O modo de transformação usa camadas para o preview
+        if !app.is_plugin_added::<LayersPlugin>() {
+            app.add_plugins(LayersPlugin);
+        }
     }
 }
 
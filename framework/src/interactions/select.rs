@@ -9,6 +9,7 @@ use super::{
     input_utils::{self, MouseWorldPosition},
     InteractionMode,
 };
+use crate::layers::{layer_top, Layer, LayerStack, ZOrder};
 
 /// Plugin para funcionalidade de seleção
 pub struct SelectPlugin;
@@ -47,7 +48,11 @@ fn can_select(mode: Res<InteractionMode>) -> bool {
 fn detect_selection_click(
     mouse_button: Res<ButtonInput<MouseButton>>,
     mouse_pos: Res<MouseWorldPosition>,
-    selectable_query: Query<(Entity, &Transform, Option<&GeometryBounds>), (With<Selectable>, Without<Selected>)>,
+    selectable_query: Query<
+        (Entity, &Transform, Option<&GeometryBounds>, Option<&Layer>, Option<&ZOrder>),
+        (With<Selectable>, Without<Selected>),
+    >,
+    layer_stack: Res<LayerStack>,
     mut select_events: EventWriter<SelectEvent>,
     mut deselect_events: EventWriter<DeselectEvent>,
     mut interaction_mode: ResMut<InteractionMode>,
@@ -62,31 +67,7 @@ fn detect_selection_click(
     }
     
     // Verificar se há uma entidade selecionável sob o cursor
-    let mut closest_entity = None;
-    let mut closest_z = f32::MIN;
-    
-    for (entity, transform, bounds) in selectable_query.iter() {
-        let is_hit = if let Some(bounds) = bounds {
-            input_utils::point_in_bounds(
-                mouse_pos.0,
-                transform.translation,
-                bounds.size,
-                transform.scale,
-            )
-        } else {
-            input_utils::point_in_bounds(
-                mouse_pos.0,
-                transform.translation,
-                Vec2::new(100.0, 100.0),
-                transform.scale,
-            )
-        };
-        
-        if is_hit && transform.translation.z > closest_z {
-            closest_z = transform.translation.z;
-            closest_entity = Some(entity);
-        }
-    }
+    let closest_entity = input_utils::find_entity_under_cursor(mouse_pos.0, &layer_stack, selectable_query.iter());
     
     if let Some(entity) = closest_entity {
         // Desselecionar a entidade atual se houver
@@ -116,7 +97,8 @@ fn apply_selection(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut select_events: EventReader<SelectEvent>,
-    transform_query: Query<(&Transform, Option<&GeometryBounds>)>,
+    transform_query: Query<(&Transform, Option<&GeometryBounds>, Option<&Layer>)>,
+    layer_stack: Res<LayerStack>,
     mut current_selection: ResMut<CurrentSelection>,
 ) {
     for event in select_events.read() {
@@ -124,7 +106,7 @@ fn apply_selection(
         commands.entity(event.entity).insert(Selected);
         
         // Obter informações da entidade selecionada
-        if let Ok((transform, bounds)) = transform_query.get(event.entity) {
+        if let Ok((transform, bounds, layer)) = transform_query.get(event.entity) {
             // Calcular tamanho da borda
             let bounds_size = bounds.map(|b| b.size).unwrap_or(Vec2::new(100.0, 100.0));
             let border_size = bounds_size * transform.scale.truncate() + Vec2::splat(10.0);
@@ -140,7 +122,7 @@ fn apply_selection(
                 .spawn((
                 Mesh2d(meshes.add(border_mesh)),
                 MeshMaterial2d(materials.add(border_material)),
-                    Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)), // Levemente acima
+                    Transform::from_translation(Vec3::new(0.0, 0.0, border_depth(&layer_stack, transform, layer))),
                     SelectionBorder {
                         parent: event.entity,
                     },
@@ -187,22 +169,32 @@ fn apply_deselection(
 
 /// Atualiza a posição/escala da borda conforme a entidade pai muda
 fn update_selection_border(
-    parent_query: Query<(&Transform, Option<&GeometryBounds>), (With<Selected>, Changed<Transform>)>,
+    parent_query: Query<
+        (&Transform, Option<&GeometryBounds>, Option<&Layer>),
+        (With<Selected>, Changed<Transform>),
+    >,
     mut border_query: Query<(&mut Transform, &SelectionBorder), Without<Selected>>,
+    layer_stack: Res<LayerStack>,
 ) {
     for (mut border_transform, selection_border) in border_query.iter_mut() {
-        if let Ok((parent_transform, bounds)) = parent_query.get(selection_border.parent) {
+        if let Ok((parent_transform, bounds, layer)) = parent_query.get(selection_border.parent) {
             // Atualizar escala da borda se necessário
             let bounds_size = bounds.map(|b| b.size).unwrap_or(Vec2::new(100.0, 100.0));
             let _new_border_size = bounds_size * parent_transform.scale.truncate() + Vec2::splat(10.0);
             
             // Nota: Em produção, você poderia querer atualizar a mesh também
             // Por simplicidade, apenas ajustamos a posição relativa aqui
-            border_transform.translation.z = 0.1; // Manter levemente acima
+            // Manter a borda acima das demais entidades da camada
+            border_transform.translation.z = border_depth(&layer_stack, parent_transform, layer);
         }
     }
 }
 
+/// `translation.z` local da borda que a coloca no topo da camada da entidade selecionada
+fn border_depth(stack: &LayerStack, parent: &Transform, layer: Option<&Layer>) -> f32 {
+    (layer_top(stack, layer) - parent.translation.z) / parent.scale.z.max(f32::EPSILON)
+}
+
 /// Cria uma mesh de borda (retângulo vazado)
 fn create_border_mesh(size: Vec2) -> Mesh {
     use bevy::render::mesh::{Indices, Mesh, PrimitiveTopology};
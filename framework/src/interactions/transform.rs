@@ -12,6 +12,7 @@ use super::{
     input_utils::{MouseWorldPosition, calculate_distance_and_angle},
     InteractionMode,
 };
+use crate::layers::{Layer, ZOrderExt};
 // Geometrics pode ser usado em implementações futuras
 
 /// Plugin para modo de transformação/criação de geometrias
@@ -144,8 +145,9 @@ fn begin_transform(
                 Transform::from_translation(Vec3::new(
                     event.start_position.x,
                     event.start_position.y,
-                    10.0, // Z alto para ficar acima de outras geometrias
+                    0.0,
                 )),
+                Layer::new("overlay"), // Camada acima de outras geometrias
                 Transforming {
                     pivot: event.start_position,
                     geometry_data: event.geometry_data.clone(),
@@ -236,8 +238,12 @@ fn confirm_transform(
             Draggable,
             Selectable,
             GeometryBounds { size: bounds_size },
+            Layer::default(),
         ));
         
+        // Nova geometria fica à frente das existentes na camada padrão
+        commands.entity(entity).bring_to_front();
+        
         // Material já será opaco na criação final
         
         confirm_events.send(ConfirmTransformEvent { entity });
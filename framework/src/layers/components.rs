@@ -0,0 +1,103 @@
+//! Componentes e recursos para camadas e ordem de desenho
+
+use bevy::prelude::*;
+
+/// Nome da camada usada por entidades sem `Layer`
+pub const DEFAULT_LAYER: &str = "default";
+
+/// Camada nomeada à qual a entidade pertence
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Layer(pub String);
+
+impl Layer {
+    /// Cria uma referência a uma camada pelo nome
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+}
+
+impl Default for Layer {
+    fn default() -> Self {
+        Self::new(DEFAULT_LAYER)
+    }
+}
+
+/// Ordem da entidade dentro da sua camada (valores maiores ficam na frente)
+///
+/// O valor é relativo: o framework ordena as entidades de cada camada por
+/// `ZOrder` e as distribui no intervalo de profundidade da camada.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ZOrder(pub i32);
+
+/// Lista ordenada de camadas (da mais ao fundo para a mais à frente)
+#[derive(Resource, Debug, Clone)]
+pub struct LayerStack {
+    layers: Vec<String>,
+}
+
+impl Default for LayerStack {
+    fn default() -> Self {
+        Self {
+            layers: vec![
+                "background".to_string(),
+                DEFAULT_LAYER.to_string(),
+                "foreground".to_string(),
+                "overlay".to_string(),
+            ],
+        }
+    }
+}
+
+impl LayerStack {
+    /// Cria uma pilha com as camadas especificadas (do fundo para a frente)
+    pub fn new(layers: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            layers: layers.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Nomes das camadas, do fundo para a frente
+    pub fn layers(&self) -> &[String] {
+        &self.layers
+    }
+
+    /// Índice da camada na pilha
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.layers.iter().position(|layer| layer == name)
+    }
+
+    /// Adiciona uma camada à frente de todas as outras
+    pub fn push_layer(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        if self.index_of(&name).is_none() {
+            self.layers.push(name);
+        }
+    }
+
+    /// Insere uma camada logo acima de outra (ou à frente de todas se `reference` não existir)
+    pub fn insert_above(&mut self, reference: &str, name: impl Into<String>) {
+        let name = name.into();
+        if self.index_of(&name).is_some() {
+            return;
+        }
+        match self.index_of(reference) {
+            Some(index) => self.layers.insert(index + 1, name),
+            None => self.layers.push(name),
+        }
+    }
+
+    /// Insere uma camada logo abaixo de outra (ou atrás de todas se `reference` não existir)
+    pub fn insert_below(&mut self, reference: &str, name: impl Into<String>) {
+        let name = name.into();
+        if self.index_of(&name).is_some() {
+            return;
+        }
+        let index = self.index_of(reference).unwrap_or(0);
+        self.layers.insert(index, name);
+    }
+
+    /// Remove uma camada (entidades nela passam a usar a camada padrão)
+    pub fn remove_layer(&mut self, name: &str) {
+        self.layers.retain(|layer| layer != name);
+    }
+}
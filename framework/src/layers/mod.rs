@@ -0,0 +1,48 @@
+//! Módulo de Camadas
+//! 
+//! Organiza a ordem de desenho das geometrias com camadas nomeadas e uma
+//! ordem relativa dentro de cada camada, em vez de valores brutos de `translation.z`.
+//! 
+//! # Exemplo de uso:
+//! ```rust
+//! use kosmos_framework::layers::prelude::*;
+//! 
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(LayersPlugin)
+//!     .run();
+//! 
+//! // Colocar uma geometria na camada de primeiro plano, à frente das demais
+//! commands.entity(circle).insert((Layer::new("foreground"), ZOrder(0)));
+//! commands.entity(square).bring_to_front();
+//! ```
+
+use bevy::prelude::*;
+
+mod components;
+mod ordering;
+
+// Re-exportar os tipos públicos
+pub use components::*;
+pub use ordering::{draw_order, layer_index, layer_top, ZOrderExt, LAYER_DEPTH};
+
+/// Plugin que mapeia camadas e `ZOrder` para `translation.z`
+pub struct LayersPlugin;
+
+impl Plugin for LayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LayerStack>().add_systems(
+            PostUpdate,
+            ordering::apply_z_order.before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// Prelude para facilitar imports
+pub mod prelude {
+    pub use super::{
+        LayersPlugin,
+        ZOrderExt,
+        components::*,
+    };
+}
@@ -0,0 +1,214 @@
+//! Mapeamento de camadas e `ZOrder` para `translation.z`
+
+use bevy::math::FloatOrd;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use super::components::{Layer, LayerStack, ZOrder, DEFAULT_LAYER};
+
+/// Intervalo de profundidade reservado para cada camada
+///
+/// A câmera 2D padrão desenha até `z = 1000`, o que comporta 100 camadas.
+pub const LAYER_DEPTH: f32 = 10.0;
+
+/// Distância máxima entre entidades consecutivas de uma camada
+const MAX_ORDER_STEP: f32 = 1.0;
+
+/// Faixa no topo de cada camada reservada para elementos de interface (ex: bordas de seleção)
+const TOP_MARGIN: f32 = 0.5;
+
+/// Entidades posicionadas pelo sistema de camadas
+///
+/// Só entidades com `Layer` ou `ZOrder` são gerenciadas; as demais mantêm o
+/// `translation.z` definido pelo usuário.
+type Ordered = Or<(With<Layer>, With<ZOrder>)>;
+
+/// Entidades gerenciadas cuja camada ou ordem mudou (ou foi adicionada)
+type OrderChanged = (Ordered, Or<(Changed<Layer>, Changed<ZOrder>)>);
+
+/// Consulta das entidades gerenciadas e de seus `Transform`
+type OrderedQuery<'w, 's> =
+    Query<'w, 's, (Entity, Option<&'static Layer>, Option<&'static ZOrder>, &'static mut Transform), Ordered>;
+
+/// Índice da camada de uma entidade na pilha (camadas desconhecidas usam a padrão)
+pub fn layer_index(stack: &LayerStack, layer: Option<&Layer>) -> usize {
+    let name = layer.map(|l| l.0.as_str()).unwrap_or(DEFAULT_LAYER);
+    stack
+        .index_of(name)
+        .or_else(|| stack.index_of(DEFAULT_LAYER))
+        .unwrap_or(0)
+}
+
+/// Chave de ordem de desenho: entidades com chave maior ficam na frente
+///
+/// Compara camada e `ZOrder` antes de `translation.z`, então o resultado não
+/// depende de `apply_z_order` já ter rodado neste quadro.
+pub fn draw_order(
+    stack: &LayerStack,
+    layer: Option<&Layer>,
+    order: Option<&ZOrder>,
+    transform: &Transform,
+) -> (usize, ZOrder, FloatOrd) {
+    (
+        layer_index(stack, layer),
+        order.copied().unwrap_or_default(),
+        FloatOrd(transform.translation.z),
+    )
+}
+
+/// `translation.z` acima de todas as entidades da camada (e abaixo da próxima camada)
+pub fn layer_top(stack: &LayerStack, layer: Option<&Layer>) -> f32 {
+    (layer_index(stack, layer) + 1) as f32 * LAYER_DEPTH - TOP_MARGIN * 0.5
+}
+
+/// Sistema que converte camada + `ZOrder` em `translation.z`
+///
+/// As entidades de cada camada são ordenadas por `ZOrder` (empates resolvidos
+/// pela ordem de criação) e distribuídas dentro do intervalo da camada. A
+/// distribuição só é refeita quando uma `Layer`/`ZOrder` é adicionada, alterada
+/// ou removida, ou quando a `LayerStack` muda.
+pub fn apply_z_order(
+    stack: Res<LayerStack>,
+    mut query: OrderedQuery,
+    changed: Query<(), OrderChanged>,
+    mut removed_layers: RemovedComponents<Layer>,
+    mut removed_orders: RemovedComponents<ZOrder>,
+) {
+    // Consumir as remoções em todo quadro, mesmo sem recalcular
+    let removed = removed_layers.read().count() + removed_orders.read().count() > 0;
+    if !stack.is_changed() && changed.is_empty() && !removed {
+        return;
+    }
+
+    let mut by_layer: HashMap<usize, Vec<(ZOrder, Entity)>> = HashMap::new();
+    for (entity, layer, order, _) in query.iter() {
+        by_layer
+            .entry(layer_index(&stack, layer))
+            .or_default()
+            .push((order.copied().unwrap_or_default(), entity));
+    }
+
+    for (index, mut entities) in by_layer {
+        entities.sort();
+        let step = ((LAYER_DEPTH - TOP_MARGIN) / (entities.len() + 1) as f32).min(MAX_ORDER_STEP);
+        let base = index as f32 * LAYER_DEPTH;
+
+        for (rank, (_, entity)) in entities.into_iter().enumerate() {
+            let z = base + (rank + 1) as f32 * step;
+            if let Ok((_, _, _, mut transform)) = query.get_mut(entity) {
+                // Evitar marcar o Transform como alterado sem necessidade
+                if transform.translation.z != z {
+                    transform.translation.z = z;
+                }
+            }
+        }
+    }
+}
+
+/// Operação de reordenação dentro da camada
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reorder {
+    Forward,
+    Backward,
+    ToFront,
+    ToBack,
+}
+
+/// Extensão para reorganizar entidades entre camadas e dentro delas
+pub trait ZOrderExt {
+    /// Move a entidade para uma camada nomeada
+    fn set_layer(&mut self, layer: impl Into<String>) -> &mut Self;
+    /// Troca de posição com a próxima entidade à frente na camada
+    fn bring_forward(&mut self) -> &mut Self;
+    /// Troca de posição com a entidade imediatamente atrás na camada
+    fn send_backward(&mut self) -> &mut Self;
+    /// Coloca a entidade à frente de todas as outras da camada
+    fn bring_to_front(&mut self) -> &mut Self;
+    /// Coloca a entidade atrás de todas as outras da camada
+    fn send_to_back(&mut self) -> &mut Self;
+}
+
+impl ZOrderExt for EntityCommands<'_> {
+    fn set_layer(&mut self, layer: impl Into<String>) -> &mut Self {
+        self.insert(Layer::new(layer));
+        self
+    }
+
+    fn bring_forward(&mut self) -> &mut Self {
+        self.queue(|entity: EntityWorldMut| reorder(entity, Reorder::Forward));
+        self
+    }
+
+    fn send_backward(&mut self) -> &mut Self {
+        self.queue(|entity: EntityWorldMut| reorder(entity, Reorder::Backward));
+        self
+    }
+
+    fn bring_to_front(&mut self) -> &mut Self {
+        self.queue(|entity: EntityWorldMut| reorder(entity, Reorder::ToFront));
+        self
+    }
+
+    fn send_to_back(&mut self) -> &mut Self {
+        self.queue(|entity: EntityWorldMut| reorder(entity, Reorder::ToBack));
+        self
+    }
+}
+
+/// Aplica uma operação de reordenação usando as entidades da mesma camada
+fn reorder(entity: EntityWorldMut, operation: Reorder) {
+    let target = entity.id();
+    let target_layer = entity.get::<Layer>().cloned();
+    let world = entity.into_world_mut();
+    let stack = world.get_resource::<LayerStack>().cloned().unwrap_or_default();
+    let target_index = layer_index(&stack, target_layer.as_ref());
+
+    // Entidades da mesma camada, ordenadas como em `apply_z_order`
+    let mut siblings: Vec<(ZOrder, Entity)> = world
+        .query_filtered::<(Entity, Option<&Layer>, Option<&ZOrder>), Ordered>()
+        .iter(world)
+        .filter(|(_, layer, _)| layer_index(&stack, *layer) == target_index)
+        .map(|(entity, _, order)| (order.copied().unwrap_or_default(), entity))
+        .collect();
+    if !siblings.iter().any(|(_, entity)| *entity == target) {
+        siblings.push((ZOrder::default(), target));
+    }
+    siblings.sort();
+
+    let position = siblings.iter().position(|(_, entity)| *entity == target).unwrap();
+    let current = siblings[position].0;
+
+    match operation {
+        Reorder::Forward | Reorder::Backward => {
+            let neighbor = match operation {
+                Reorder::Forward => siblings.get(position + 1),
+                _ => position.checked_sub(1).and_then(|i| siblings.get(i)),
+            };
+            let Some(&(neighbor_order, neighbor)) = neighbor else {
+                return;
+            };
+
+            // Trocar as ordens; em caso de empate, abrir espaço de uma unidade
+            let (new_target, new_neighbor) = if neighbor_order != current {
+                (neighbor_order, current)
+            } else if operation == Reorder::Forward {
+                (ZOrder(current.0 + 1), current)
+            } else {
+                (ZOrder(current.0 - 1), current)
+            };
+            world.entity_mut(target).insert(new_target);
+            world.entity_mut(neighbor).insert(new_neighbor);
+        }
+        Reorder::ToFront => {
+            let (max, _) = siblings.last().unwrap();
+            if siblings.len() > 1 && siblings[siblings.len() - 1].1 != target {
+                world.entity_mut(target).insert(ZOrder(max.0 + 1));
+            }
+        }
+        Reorder::ToBack => {
+            let (min, _) = siblings.first().unwrap();
+            if siblings.len() > 1 && siblings[0].1 != target {
+                world.entity_mut(target).insert(ZOrder(min.0 - 1));
+            }
+        }
+    }
+}
@@ -1,4 +1,8 @@
 pub use bevy;
 pub mod animations;
+pub mod effects;
 pub mod geometrics;
 pub mod interactions;
+pub mod layers;
+pub mod lottie;
+pub mod render;
@@ -0,0 +1,579 @@
+//! Conversão das camadas Lottie em entidades e timelines do Kosmos
+//!
+//! O Lottie usa Y para baixo com a origem no canto superior esquerdo da
+//! composição; no Kosmos Y é para cima e a composição fica centrada na
+//! posição pedida. Cada camada e cada grupo vira uma entidade com a sua
+//! transformação (posição, rotação e escala animáveis) e cada preenchimento ou
+//! contorno vira uma mesh filha, com a cor e a opacidade animáveis.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+use super::model::{
+    Animated, Bezier, Composition, Layer, Position, Shape, TransformData, NULL_LAYER, SHAPE_LAYER,
+};
+use crate::animations::{
+    AnimatableProperty, AnimationComponent, AnimationMode, AnimationTimeline, ColorSpace, Easing,
+    FieldValue, Keyframe,
+};
+use crate::geometrics::{
+    text, Geometrics, StrokeCap, StrokeGeometry, StrokeJoin, StrokeOptions, StrokeTrim, TrimMode,
+};
+
+/// Distância em Z entre camadas
+const LAYER_DEPTH: f32 = 1.0;
+
+/// Distância em Z entre meshes da mesma camada
+const SHAPE_DEPTH: f32 = 1e-4;
+
+/// Segmentos usados para amostrar cada curva de Bézier
+const CURVE_SEGMENTS: usize = 16;
+
+/// Segmentos usados para amostrar elipses
+const ELLIPSE_SEGMENTS: usize = 64;
+
+/// Segmentos usados em cada canto arredondado de retângulos
+const CORNER_SEGMENTS: usize = 8;
+
+/// Caminho em coordenadas Lottie (pontos, fechado)
+type LottiePath = (Vec<Vec2>, bool);
+
+/// Opacidades que compõem o alpha de uma mesh
+#[derive(Clone, Copy)]
+struct MeshOpacity<'a> {
+    /// Opacidade do preenchimento ou contorno (0-100)
+    shape: &'a Animated,
+    /// Produto das opacidades estáticas dos grupos acima (0-1)
+    groups: f32,
+    /// Opacidade da camada, quando animada (0-100)
+    layer: Option<&'a Animated>,
+}
+
+/// Estado da importação de uma composição
+pub(crate) struct Importer<'a> {
+    pub composition: &'a Composition,
+    pub meshes: &'a mut Assets<Mesh>,
+    pub materials: &'a mut Assets<ColorMaterial>,
+    pub mode: AnimationMode,
+    /// Ordem de desenho da próxima mesh na camada atual
+    order: u32,
+}
+
+impl<'a> Importer<'a> {
+    pub fn new(
+        composition: &'a Composition,
+        meshes: &'a mut Assets<Mesh>,
+        materials: &'a mut Assets<ColorMaterial>,
+        mode: AnimationMode,
+    ) -> Self {
+        Self {
+            composition,
+            meshes,
+            materials,
+            mode,
+            order: 0,
+        }
+    }
+
+    /// Cria as entidades de todas as camadas como filhas de `root`
+    pub fn spawn_layers(&mut self, commands: &mut Commands, root: Entity) {
+        let composition = self.composition;
+        let layers: Vec<(usize, &Layer)> = composition
+            .layers
+            .iter()
+            .enumerate()
+            .filter(|(_, layer)| !layer.hd)
+            .collect();
+        let by_index: HashMap<i64, usize> = layers
+            .iter()
+            .filter_map(|(i, layer)| layer.ind.map(|ind| (ind, *i)))
+            .collect();
+        let center = Vec2::new(composition.width, composition.height) * 0.5;
+
+        let mut entities = HashMap::new();
+        for &(i, layer) in &layers {
+            if layer.ty != SHAPE_LAYER && layer.ty != NULL_LAYER {
+                warn!(
+                    "Camada Lottie '{}' do tipo {} não suportada; apenas a transformação é importada",
+                    layer.nm, layer.ty
+                );
+            }
+
+            let parent = layer.parent.and_then(|parent| by_index.get(&parent).copied());
+            // Posição relativa à âncora do pai ou ao centro da composição
+            let (origin, depth) = match parent {
+                Some(parent) => (
+                    anchor_at(&composition.layers[parent].ks, composition.in_point),
+                    (parent as f32 - i as f32) * LAYER_DEPTH,
+                ),
+                None => (center, (composition.layers.len() - i) as f32 * LAYER_DEPTH),
+            };
+
+            let entity = self.spawn_node(commands, &layer.nm, &layer.ks, origin, depth);
+            if layer.ty == SHAPE_LAYER {
+                self.order = 0;
+                let layer_anchor = anchor_at(&layer.ks, composition.in_point);
+                self.spawn_items(commands, &layer.shapes, layer_anchor, 1.0, layer.ks.o.as_ref(), entity);
+            }
+            entities.insert(i, (entity, parent));
+        }
+
+        for (entity, parent) in entities.values() {
+            let parent = parent
+                .and_then(|parent| entities.get(&parent))
+                .map(|(entity, _)| *entity)
+                .unwrap_or(root);
+            commands.entity(parent).add_child(*entity);
+        }
+    }
+
+    /// Cria a entidade de uma camada ou grupo com a sua transformação
+    fn spawn_node(
+        &mut self,
+        commands: &mut Commands,
+        name: &str,
+        ks: &TransformData,
+        origin: Vec2,
+        depth: f32,
+    ) -> Entity {
+        let frame = self.composition.in_point;
+        let mut transform = Transform::from_translation(to_local(position_at(ks, frame), origin).extend(depth));
+        transform.rotation = Quat::from_rotation_z(rotation_at(ks, frame));
+        transform.scale = scale_at(ks, frame).extend(1.0);
+
+        let mut timeline = self.timeline(name);
+        if let Some(Position::Combined(p)) = &ks.p {
+            self.add_track(&mut timeline, "position", p, |v| {
+                AnimatableProperty::Position(to_local(vec2(v), origin))
+            });
+        } else if let Some(Position::Split { x, y }) = &ks.p {
+            if x.is_animated() || y.is_animated() {
+                self.bake_track(&mut timeline, "position", |frame| {
+                    AnimatableProperty::Position(to_local(Vec2::new(x.scalar_at(frame), y.scalar_at(frame)), origin))
+                });
+            }
+        }
+        if let Some(r) = &ks.r {
+            self.add_track(&mut timeline, "rotation", r, |v| {
+                AnimatableProperty::Rotation(-first(v).to_radians())
+            });
+        }
+        if let Some(s) = &ks.s {
+            self.add_track(&mut timeline, "scale", s, |v| AnimatableProperty::Scale(vec2(v) / 100.0));
+        }
+
+        let mut entity = commands.spawn((Name::new(name.to_string()), transform, Visibility::default()));
+        if !timeline.tracks.is_empty() {
+            entity.insert(AnimationComponent::new(self.finish(timeline)));
+        }
+        entity.id()
+    }
+
+    /// Cria as entidades dos itens de um grupo (em ordem de desenho)
+    ///
+    /// No Lottie o primeiro item fica na frente e cada preenchimento ou
+    /// contorno desenha os caminhos que vêm antes dele no grupo, inclusive os
+    /// de subgrupos. Contornos depois de um trim path mostram só o trecho
+    /// recortado por ele.
+    fn spawn_items(
+        &mut self,
+        commands: &mut Commands,
+        items: &[Shape],
+        anchor: Vec2,
+        opacity: f32,
+        layer_opacity: Option<&Animated>,
+        parent: Entity,
+    ) {
+        let frame = self.composition.in_point;
+        let mut above: Vec<LottiePath> = Vec::new();
+        let mut trim = None;
+        let mut paths_above = Vec::with_capacity(items.len());
+        for item in items {
+            paths_above.push((above.clone(), trim));
+            above.extend(item_paths(item, frame));
+            if matches!(item, Shape::Trim { hd: false, .. }) {
+                trim = Some(item);
+            }
+        }
+
+        for (item, (paths, trim)) in items.iter().zip(paths_above).rev() {
+            let child = match item {
+                Shape::Group { it, hd: false } => {
+                    let ks = group_transform(it);
+                    let group_opacity = ks.o.as_ref().map_or(100.0, |o| o.scalar_at(frame)) / 100.0;
+                    let entity = self.spawn_node(commands, "group", &ks, anchor, 0.0);
+                    let group_anchor = anchor_at(&ks, frame);
+                    self.spawn_items(commands, it, group_anchor, opacity * group_opacity, layer_opacity, entity);
+                    entity
+                }
+                Shape::Fill { c, o, hd: false } => {
+                    let contours = paths
+                        .iter()
+                        .map(|(points, _)| points.iter().map(|p| to_local(*p, anchor)).collect())
+                        .collect();
+                    let mesh = Geometrics::create_mesh_with_holes(text::group_contours(contours));
+                    let timeline = self.timeline("shape");
+                    let opacity = MeshOpacity { shape: o, groups: opacity, layer: layer_opacity };
+                    self.spawn_mesh(commands, mesh, c, opacity, timeline)
+                }
+                Shape::Stroke { c, o, w, lc, lj, ml, hd: false } => {
+                    let polylines: Vec<LottiePath> = paths
+                        .iter()
+                        .map(|(points, closed)| (points.iter().map(|p| to_local(*p, anchor)).collect(), *closed))
+                        .collect();
+                    let options = StrokeOptions::new(w.scalar_at(frame))
+                        .with_cap(match lc {
+                            Some(2) => StrokeCap::Round,
+                            Some(3) => StrokeCap::Square,
+                            _ => StrokeCap::Butt,
+                        })
+                        .with_join(match lj {
+                            Some(2) => StrokeJoin::Round,
+                            Some(3) => StrokeJoin::Bevel,
+                            _ => StrokeJoin::Miter(ml.unwrap_or(4.0)),
+                        });
+                    let mesh = Geometrics::create_stroke_mesh(&polylines, &options);
+                    let mut timeline = self.timeline("shape");
+                    let trim = trim.map(|trim| self.stroke_trim(&mut timeline, trim));
+                    let opacity = MeshOpacity { shape: o, groups: opacity, layer: layer_opacity };
+                    let entity = self.spawn_mesh(commands, mesh, c, opacity, timeline);
+                    if let Some(trim) = trim {
+                        // A mesh recortada é gerada pelo `AnimationPlugin` ao inserir o trim
+                        commands.entity(entity).insert((StrokeGeometry { polylines, options }, trim));
+                    }
+                    entity
+                }
+                _ => continue,
+            };
+            commands.entity(parent).add_child(child);
+        }
+    }
+
+    /// Cria a mesh de um preenchimento ou contorno com a sua cor animada
+    fn spawn_mesh(
+        &mut self,
+        commands: &mut Commands,
+        mesh: Mesh,
+        color: &Animated,
+        opacity: MeshOpacity,
+        mut timeline: AnimationTimeline,
+    ) -> Entity {
+        self.order += 1;
+        let MeshOpacity { shape: shape_opacity, groups: static_opacity, layer: layer_opacity } = opacity;
+        let frame = self.composition.in_point;
+        let alpha_at = |frame: f32| {
+            let layer = layer_opacity.map_or(100.0, |o| o.scalar_at(frame));
+            shape_opacity.scalar_at(frame) / 100.0 * layer / 100.0 * static_opacity
+        };
+        let color_at = |value: &[f32], alpha: f32| {
+            Color::srgba(
+                value.first().copied().unwrap_or(0.0),
+                value.get(1).copied().unwrap_or(0.0),
+                value.get(2).copied().unwrap_or(0.0),
+                alpha,
+            )
+        };
+
+        let layer_animated = layer_opacity.is_some_and(Animated::is_animated);
+        let opacity_animated = shape_opacity.is_animated() || layer_animated;
+
+        if color.is_animated() && opacity_animated {
+            // Cor e opacidade com keyframes diferentes: amostrar cada quadro
+            self.bake_track(&mut timeline, "color", |frame| {
+                AnimatableProperty::Color(color_at(&color.value_at(frame), alpha_at(frame)))
+            });
+            if let Some(track) = timeline.tracks.get_mut("color") {
+                track.color_space = ColorSpace::Srgb;
+            }
+        } else if color.is_animated() {
+            // Opacidade constante embutida no alpha das cores
+            for (i, keyframe) in color.keyframes().iter().enumerate() {
+                let mut key = Keyframe::new(
+                    self.seconds(keyframe.time),
+                    AnimatableProperty::Color(color_at(&keyframe.value, alpha_at(keyframe.time))),
+                    previous_easing(color, i),
+                );
+                key.color_space = Some(ColorSpace::Srgb);
+                timeline.add_keyframe("color", key);
+            }
+        } else {
+            match (shape_opacity.is_animated(), layer_animated) {
+                (true, false) => {
+                    let scale = layer_opacity.map_or(100.0, |o| o.scalar_at(frame)) / 100.0 * static_opacity;
+                    self.add_track(&mut timeline, "opacity", shape_opacity, |v| {
+                        AnimatableProperty::Opacity(first(v) / 100.0 * scale)
+                    });
+                }
+                (false, true) => {
+                    let scale = shape_opacity.scalar_at(frame) / 100.0 * static_opacity;
+                    if let Some(layer_opacity) = layer_opacity {
+                        self.add_track(&mut timeline, "opacity", layer_opacity, |v| {
+                            AnimatableProperty::Opacity(first(v) / 100.0 * scale)
+                        });
+                    }
+                }
+                (true, true) => {
+                    self.bake_track(&mut timeline, "opacity", |frame| AnimatableProperty::Opacity(alpha_at(frame)));
+                }
+                (false, false) => {}
+            }
+        }
+
+        let material = ColorMaterial::from(color_at(&color.value_at(frame), alpha_at(frame)));
+        let mut entity = commands.spawn((
+            Mesh2d(self.meshes.add(mesh)),
+            MeshMaterial2d(self.materials.add(material)),
+            Transform::from_xyz(0.0, 0.0, self.order as f32 * SHAPE_DEPTH),
+        ));
+        if !timeline.tracks.is_empty() {
+            entity.insert(AnimationComponent::new(self.finish(timeline)));
+        }
+        entity.id()
+    }
+
+    /// Trecho visível inicial de um trim path, com as tracks dos valores animados
+    fn stroke_trim(&self, timeline: &mut AnimationTimeline, trim: &Shape) -> StrokeTrim {
+        let Shape::Trim { s, e, o, m, .. } = trim else {
+            return StrokeTrim::default();
+        };
+        let frame = self.composition.in_point;
+        let fields = [("start", s, 0.0, 100.0), ("end", e, 100.0, 100.0), ("offset", o, 0.0, 360.0)];
+        let mut values = [0.0; 3];
+        for (i, (field, value, default, scale)) in fields.into_iter().enumerate() {
+            let Some(value) = value else {
+                values[i] = default / scale;
+                continue;
+            };
+            let path: Arc<str> = format!("StrokeTrim::{field}").into();
+            self.add_track(timeline, &path, value, |v| {
+                AnimatableProperty::Field(path.clone(), FieldValue::Float(first(v) / scale))
+            });
+            values[i] = value.scalar_at(frame) / scale;
+        }
+
+        StrokeTrim::new(values[0], values[1])
+            .with_offset(values[2])
+            .with_mode(match m {
+                Some(2) => TrimMode::Sequential,
+                _ => TrimMode::Simultaneous,
+            })
+    }
+
+    fn timeline(&self, name: &str) -> AnimationTimeline {
+        let mut timeline = AnimationTimeline::new(name.to_string());
+        timeline.set_mode(self.mode);
+        timeline
+    }
+
+    /// Completa a duração da timeline até o fim da composição e a inicia
+    fn finish(&self, mut timeline: AnimationTimeline) -> AnimationTimeline {
+        let composition = self.composition;
+        let end = (composition.out_point - composition.in_point) / composition.frame_rate;
+        timeline.duration = timeline.duration.max(end);
+        timeline.play();
+        timeline
+    }
+
+    /// Tempo em segundos de um quadro
+    fn seconds(&self, frame: f32) -> f32 {
+        (frame - self.composition.in_point) / self.composition.frame_rate
+    }
+
+    /// Converte os keyframes de uma propriedade, com o easing de Bézier de cada trecho
+    fn add_track(
+        &self,
+        timeline: &mut AnimationTimeline,
+        track: &str,
+        value: &Animated,
+        map: impl Fn(&[f32]) -> AnimatableProperty,
+    ) {
+        if !value.is_animated() {
+            return;
+        }
+        let keyframes = value.keyframes().iter().enumerate().map(|(i, keyframe)| {
+            Keyframe::new(self.seconds(keyframe.time), map(&keyframe.value), previous_easing(value, i))
+        });
+        timeline.add_keyframes(track, keyframes);
+    }
+
+    /// Amostra uma propriedade calculada em cada quadro da composição
+    fn bake_track(&self, timeline: &mut AnimationTimeline, track: &str, sample: impl Fn(f32) -> AnimatableProperty) {
+        let composition = self.composition;
+        // Contagem inteira: o intervalo já foi limitado em `LottieAnimation::from_composition`
+        let count = (composition.out_point - composition.in_point).floor() as u32 + 1;
+        let frames = (0..count).map(|i| composition.in_point + i as f32);
+        timeline.add_keyframes(
+            track,
+            frames.map(|frame| Keyframe::new(self.seconds(frame), sample(frame), Easing::Linear)),
+        );
+    }
+}
+
+/// Easing do trecho que termina no keyframe `index`
+fn previous_easing(value: &Animated, index: usize) -> Easing {
+    index
+        .checked_sub(1)
+        .map(|previous| value.keyframes()[previous].easing())
+        .unwrap_or(Easing::Linear)
+}
+
+/// Converte um ponto Lottie (Y para baixo) para o espaço local de um nó
+fn to_local(point: Vec2, origin: Vec2) -> Vec2 {
+    Vec2::new(point.x - origin.x, origin.y - point.y)
+}
+
+fn first(value: &[f32]) -> f32 {
+    value.first().copied().unwrap_or(0.0)
+}
+
+fn vec2(value: &[f32]) -> Vec2 {
+    Vec2::new(first(value), value.get(1).copied().unwrap_or(0.0))
+}
+
+/// Ponto de âncora de uma transformação (as âncoras animadas usam o valor inicial)
+pub(crate) fn anchor_at(ks: &TransformData, frame: f32) -> Vec2 {
+    ks.a.as_ref().map_or(Vec2::ZERO, |a| a.vec2_at(frame))
+}
+
+fn position_at(ks: &TransformData, frame: f32) -> Vec2 {
+    match &ks.p {
+        Some(Position::Combined(p)) => p.vec2_at(frame),
+        Some(Position::Split { x, y }) => Vec2::new(x.scalar_at(frame), y.scalar_at(frame)),
+        None => Vec2::ZERO,
+    }
+}
+
+/// Rotação em radianos no sentido anti-horário
+fn rotation_at(ks: &TransformData, frame: f32) -> f32 {
+    -ks.r.as_ref().map_or(0.0, |r| r.scalar_at(frame)).to_radians()
+}
+
+fn scale_at(ks: &TransformData, frame: f32) -> Vec2 {
+    ks.s.as_ref().map_or(Vec2::ONE, |s| s.vec2_at(frame) / 100.0)
+}
+
+/// Transformação de um grupo (o item `tr`)
+fn group_transform(items: &[Shape]) -> TransformData {
+    items
+        .iter()
+        .find_map(|item| match item {
+            Shape::Transform(ks) => Some(ks.clone()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Caminhos de um item no espaço do grupo que o contém
+fn item_paths(item: &Shape, frame: f32) -> Vec<LottiePath> {
+    match item {
+        Shape::Path { ks, hd: false } => vec![bezier_points(&ks.bezier)],
+        Shape::Rect { p, s, r, hd: false } => {
+            let radius = r.as_ref().map_or(0.0, |r| r.scalar_at(frame));
+            vec![(rect_points(p.vec2_at(frame), s.vec2_at(frame), radius), true)]
+        }
+        Shape::Ellipse { p, s, hd: false } => {
+            let center = p.vec2_at(frame);
+            let radius = s.vec2_at(frame) * 0.5;
+            let points = (0..ELLIPSE_SEGMENTS)
+                .map(|i| {
+                    let angle = i as f32 / ELLIPSE_SEGMENTS as f32 * std::f32::consts::TAU;
+                    center + Vec2::new(angle.cos(), angle.sin()) * radius
+                })
+                .collect();
+            vec![(points, true)]
+        }
+        Shape::Group { it, hd: false } => {
+            let ks = group_transform(it);
+            let offset = position_at(&ks, frame);
+            let anchor = anchor_at(&ks, frame);
+            // Rotação no sentido horário em Y para baixo = rotação positiva da matriz
+            let rotation = Vec2::from_angle(ks.r.as_ref().map_or(0.0, |r| r.scalar_at(frame)).to_radians());
+            let scale = scale_at(&ks, frame);
+            let mut above = Vec::new();
+            for item in it {
+                above.extend(item_paths(item, frame));
+            }
+            above
+                .into_iter()
+                .map(|(points, closed)| {
+                    let points = points
+                        .into_iter()
+                        .map(|p| offset + rotation.rotate((p - anchor) * scale))
+                        .collect();
+                    (points, closed)
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Amostra um caminho de Bézier do Lottie
+fn bezier_points(bezier: &Bezier) -> LottiePath {
+    let vertex = |i: usize| Vec2::from_array(bezier.v[i]);
+    let tangent = |tangents: &[[f32; 2]], i: usize| tangents.get(i).map_or(Vec2::ZERO, |t| Vec2::from_array(*t));
+
+    let count = bezier.v.len();
+    let mut points = Vec::new();
+    if count == 0 {
+        return (points, bezier.c);
+    }
+    points.push(vertex(0));
+
+    let segments = if bezier.c { count } else { count - 1 };
+    for i in 0..segments {
+        let next = (i + 1) % count;
+        let p0 = vertex(i);
+        let p3 = vertex(next);
+        let out = tangent(&bezier.o, i);
+        let into = tangent(&bezier.i, next);
+
+        if out == Vec2::ZERO && into == Vec2::ZERO {
+            points.push(p3);
+            continue;
+        }
+        let (p1, p2) = (p0 + out, p3 + into);
+        for step in 1..=CURVE_SEGMENTS {
+            let t = step as f32 / CURVE_SEGMENTS as f32;
+            let mt = 1.0 - t;
+            points.push(p0 * mt * mt * mt + p1 * 3.0 * mt * mt * t + p2 * 3.0 * mt * t * t + p3 * t * t * t);
+        }
+    }
+
+    // O ponto de fechamento repete o primeiro
+    if bezier.c && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    (points, bezier.c)
+}
+
+/// Pontos de um retângulo com cantos arredondados
+fn rect_points(center: Vec2, size: Vec2, radius: f32) -> Vec<Vec2> {
+    let half = size * 0.5;
+    let radius = radius.clamp(0.0, half.x.min(half.y));
+    if radius <= 0.0 {
+        return vec![
+            center + Vec2::new(half.x, -half.y),
+            center + Vec2::new(half.x, half.y),
+            center + Vec2::new(-half.x, half.y),
+            center + Vec2::new(-half.x, -half.y),
+        ];
+    }
+
+    // Centros dos cantos e ângulo inicial de cada arco (Y para baixo)
+    let corners = [
+        (Vec2::new(half.x - radius, -half.y + radius), -std::f32::consts::FRAC_PI_2),
+        (Vec2::new(half.x - radius, half.y - radius), 0.0),
+        (Vec2::new(-half.x + radius, half.y - radius), std::f32::consts::FRAC_PI_2),
+        (Vec2::new(-half.x + radius, -half.y + radius), std::f32::consts::PI),
+    ];
+    corners
+        .iter()
+        .flat_map(|&(corner, start)| {
+            (0..=CORNER_SEGMENTS).map(move |i| {
+                let angle = start + i as f32 / CORNER_SEGMENTS as f32 * std::f32::consts::FRAC_PI_2;
+                center + corner + Vec2::new(angle.cos(), angle.sin()) * radius
+            })
+        })
+        .collect()
+}
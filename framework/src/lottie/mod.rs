@@ -0,0 +1,190 @@
+//! Módulo de Importação Lottie
+//!
+//! Importa animações exportadas do After Effects (via Bodymovin) em JSON Lottie,
+//! criando entidades do `Geometrics` animadas por `AnimationTimeline`s.
+//!
+//! Subconjunto suportado:
+//! - Camadas de forma (e camadas nulas, usadas como pais na hierarquia)
+//! - Grupos, caminhos de Bézier, retângulos (com cantos arredondados) e elipses
+//! - Preenchimentos e contornos (espessura, pontas e junções)
+//! - Transformações de camadas e grupos: posição, rotação e escala animadas,
+//!   com o easing de Bézier de cada keyframe
+//! - Cor e opacidade animadas de preenchimentos, contornos e camadas
+//! - Trim paths animados nos contornos do mesmo grupo
+//!
+//! Limitações: a geometria, a âncora, a espessura dos contornos e a opacidade
+//! dos grupos usam o valor do primeiro quadro; caminhos de movimento curvos
+//! (tangentes espaciais) viram retas; as camadas ficam visíveis durante toda a
+//! composição; trim paths não recortam preenchimentos nem contornos de grupos
+//! externos; máscaras, mattes, gradientes, textos, imagens e pré-composições
+//! são ignorados.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! use kosmos_framework::lottie::prelude::*;
+//!
+//! fn setup(
+//!     mut commands: Commands,
+//!     mut meshes: ResMut<Assets<Mesh>>,
+//!     mut materials: ResMut<Assets<ColorMaterial>>,
+//! ) {
+//!     let animation = LottieAnimation::load("assets/loader.json").expect("Lottie inválido");
+//!     let root = animation.spawn(&mut commands, &mut meshes, &mut materials, Vec2::ZERO);
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::fmt;
+use std::path::Path;
+use crate::animations::AnimationMode;
+
+mod convert;
+mod model;
+
+/// Número máximo de quadros de uma composição
+const MAX_FRAMES: f32 = 100_000.0;
+
+/// Animação Lottie carregada
+#[derive(Debug, Clone)]
+pub struct LottieAnimation {
+    composition: model::Composition,
+    mode: AnimationMode,
+}
+
+impl LottieAnimation {
+    /// Lê uma animação a partir do texto JSON
+    pub fn from_json(text: &str) -> Result<Self, LottieError> {
+        Self::from_composition(serde_json::from_str(text)?)
+    }
+
+    /// Lê uma animação a partir dos bytes do JSON
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, LottieError> {
+        Self::from_composition(serde_json::from_slice(bytes)?)
+    }
+
+    /// Valida o tempo da composição antes de aceitá-la
+    ///
+    /// Taxa de quadros nula ou intervalos enormes gerariam tempos infinitos
+    /// ou milhões de keyframes amostrados na importação.
+    fn from_composition(composition: model::Composition) -> Result<Self, LottieError> {
+        let (frame_rate, in_point, out_point) =
+            (composition.frame_rate, composition.in_point, composition.out_point);
+        if !(frame_rate.is_finite() && frame_rate > 0.0) {
+            return Err(LottieError::InvalidTiming(format!("taxa de quadros inválida: {frame_rate}")));
+        }
+        if !(in_point.is_finite() && out_point.is_finite() && out_point >= in_point) {
+            return Err(LottieError::InvalidTiming(format!(
+                "intervalo de quadros inválido: {in_point}..{out_point}"
+            )));
+        }
+        if out_point - in_point > MAX_FRAMES {
+            return Err(LottieError::InvalidTiming(format!(
+                "composição longa demais: {} quadros (máximo {MAX_FRAMES})",
+                out_point - in_point
+            )));
+        }
+
+        Ok(Self {
+            composition,
+            mode: AnimationMode::Loop,
+        })
+    }
+
+    /// Lê uma animação de um arquivo JSON
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LottieError> {
+        Self::from_slice(&std::fs::read(path)?)
+    }
+
+    /// Define o modo de execução das timelines criadas (padrão: `Loop`)
+    pub fn with_mode(mut self, mode: AnimationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Nome da composição
+    pub fn name(&self) -> &str {
+        &self.composition.nm
+    }
+
+    /// Tamanho da composição
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.composition.width, self.composition.height)
+    }
+
+    /// Quadros por segundo da composição
+    pub fn frame_rate(&self) -> f32 {
+        self.composition.frame_rate
+    }
+
+    /// Duração da composição em segundos
+    pub fn duration(&self) -> f32 {
+        (self.composition.out_point - self.composition.in_point) / self.composition.frame_rate
+    }
+
+    /// Cria as entidades da animação centradas em `position`
+    ///
+    /// Retorna a entidade raiz; as camadas são filhas dela, então mover,
+    /// girar ou escalar a raiz afeta a composição inteira.
+    pub fn spawn(
+        &self,
+        commands: &mut Commands,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        materials: &mut ResMut<Assets<ColorMaterial>>,
+        position: Vec2,
+    ) -> Entity {
+        let root = commands
+            .spawn((
+                Name::new(self.composition.nm.clone()),
+                Transform::from_translation(position.extend(0.0)),
+                Visibility::default(),
+            ))
+            .id();
+
+        convert::Importer::new(&self.composition, meshes, materials, self.mode)
+            .spawn_layers(commands, root);
+        root
+    }
+}
+
+/// Erro ao ler uma animação Lottie
+#[derive(Debug)]
+pub enum LottieError {
+    /// Falha ao ler o arquivo
+    Io(std::io::Error),
+    /// JSON inválido ou fora do formato Lottie
+    Json(serde_json::Error),
+    /// Taxa de quadros ou intervalo de quadros inválido
+    InvalidTiming(String),
+}
+
+impl fmt::Display for LottieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LottieError::Io(error) => write!(f, "erro ao ler Lottie: {error}"),
+            LottieError::Json(error) => write!(f, "Lottie inválido: {error}"),
+            LottieError::InvalidTiming(message) => write!(f, "Lottie inválido: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for LottieError {}
+
+impl From<std::io::Error> for LottieError {
+    fn from(error: std::io::Error) -> Self {
+        LottieError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for LottieError {
+    fn from(error: serde_json::Error) -> Self {
+        LottieError::Json(error)
+    }
+}
+
+/// Prelude para facilitar imports
+pub mod prelude {
+    pub use super::{
+        LottieAnimation,
+        LottieError,
+    };
+}
@@ -0,0 +1,349 @@
+//! Estruturas do JSON Lottie (subconjunto de camadas de forma)
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::animations::{Easing, StepJump};
+
+/// Tipo de camada de forma
+pub(crate) const SHAPE_LAYER: u8 = 4;
+
+/// Tipo de camada nula (só transformação, usada como pai)
+pub(crate) const NULL_LAYER: u8 = 3;
+
+/// Composição Lottie
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct Composition {
+    #[serde(default)]
+    pub nm: String,
+    #[serde(rename = "fr")]
+    pub frame_rate: f32,
+    #[serde(rename = "ip")]
+    pub in_point: f32,
+    #[serde(rename = "op")]
+    pub out_point: f32,
+    #[serde(rename = "w")]
+    pub width: f32,
+    #[serde(rename = "h")]
+    pub height: f32,
+    #[serde(default)]
+    pub layers: Vec<Layer>,
+}
+
+/// Camada da composição
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct Layer {
+    #[serde(default)]
+    pub nm: String,
+    pub ty: u8,
+    #[serde(default)]
+    pub ind: Option<i64>,
+    #[serde(default)]
+    pub parent: Option<i64>,
+    #[serde(default)]
+    pub ks: TransformData,
+    #[serde(default)]
+    pub shapes: Vec<Shape>,
+    #[serde(default)]
+    pub hd: bool,
+}
+
+/// Transformação de uma camada ou grupo
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct TransformData {
+    /// Ponto de âncora
+    #[serde(default)]
+    pub a: Option<Animated>,
+    /// Posição
+    #[serde(default)]
+    pub p: Option<Position>,
+    /// Escala em porcentagem
+    #[serde(default)]
+    pub s: Option<Animated>,
+    /// Rotação em graus (sentido horário)
+    #[serde(default)]
+    pub r: Option<Animated>,
+    /// Opacidade de 0 a 100
+    #[serde(default)]
+    pub o: Option<Animated>,
+}
+
+/// Posição combinada ou separada por eixo
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Position {
+    Split { x: Animated, y: Animated },
+    Combined(Animated),
+}
+
+/// Item de um grupo de formas
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "ty")]
+pub(crate) enum Shape {
+    /// Grupo
+    #[serde(rename = "gr")]
+    Group {
+        #[serde(default)]
+        it: Vec<Shape>,
+        #[serde(default)]
+        hd: bool,
+    },
+    /// Caminho de Bézier
+    #[serde(rename = "sh")]
+    Path {
+        ks: AnimatedBezier,
+        #[serde(default)]
+        hd: bool,
+    },
+    /// Retângulo (centro, tamanho e raio dos cantos)
+    #[serde(rename = "rc")]
+    Rect {
+        p: Animated,
+        s: Animated,
+        #[serde(default)]
+        r: Option<Animated>,
+        #[serde(default)]
+        hd: bool,
+    },
+    /// Elipse (centro e tamanho)
+    #[serde(rename = "el")]
+    Ellipse {
+        p: Animated,
+        s: Animated,
+        #[serde(default)]
+        hd: bool,
+    },
+    /// Preenchimento
+    #[serde(rename = "fl")]
+    Fill {
+        c: Animated,
+        o: Animated,
+        #[serde(default)]
+        hd: bool,
+    },
+    /// Contorno
+    #[serde(rename = "st")]
+    Stroke {
+        c: Animated,
+        o: Animated,
+        w: Animated,
+        /// Ponta: 1 reta, 2 redonda, 3 quadrada
+        #[serde(default)]
+        lc: Option<u8>,
+        /// Junção: 1 viva, 2 redonda, 3 cortada
+        #[serde(default)]
+        lj: Option<u8>,
+        /// Limite da junção viva
+        #[serde(default)]
+        ml: Option<f32>,
+        #[serde(default)]
+        hd: bool,
+    },
+    /// Trim path: trecho visível dos caminhos anteriores
+    #[serde(rename = "tm")]
+    Trim {
+        /// Início em porcentagem (padrão 0)
+        #[serde(default)]
+        s: Option<Animated>,
+        /// Fim em porcentagem (padrão 100)
+        #[serde(default)]
+        e: Option<Animated>,
+        /// Deslocamento em graus (padrão 0)
+        #[serde(default)]
+        o: Option<Animated>,
+        /// Modo: 1 simultâneo, 2 individual (em sequência)
+        #[serde(default)]
+        m: Option<u8>,
+        #[serde(default)]
+        hd: bool,
+    },
+    /// Transformação do grupo
+    #[serde(rename = "tr")]
+    Transform(TransformData),
+    /// Itens não suportados (gradientes, repetidores, ...)
+    #[serde(other)]
+    Unsupported,
+}
+
+/// Keyframe de uma propriedade animada
+#[derive(Debug, Clone)]
+pub(crate) struct LottieKeyframe {
+    /// Tempo em quadros
+    pub time: f32,
+    /// Valor no keyframe
+    pub value: Vec<f32>,
+    /// Tangente de saída do easing (`o`)
+    pub ease_out: Option<Vec2>,
+    /// Tangente de entrada do easing do próximo trecho (`i`)
+    pub ease_in: Option<Vec2>,
+    /// Se o valor é mantido até o próximo keyframe
+    pub hold: bool,
+}
+
+/// Propriedade estática ou animada (`{"a": 0|1, "k": ...}`)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawAnimated")]
+pub(crate) enum Animated {
+    Static(Vec<f32>),
+    Keyframes(Vec<LottieKeyframe>),
+}
+
+/// Forma serializada de uma propriedade
+#[derive(Deserialize)]
+struct RawAnimated {
+    k: Value,
+}
+
+impl From<RawAnimated> for Animated {
+    fn from(raw: RawAnimated) -> Self {
+        let Some(items) = raw.k.as_array().filter(|items| items.iter().any(Value::is_object)) else {
+            return Animated::Static(numbers(&raw.k));
+        };
+
+        let mut keyframes: Vec<LottieKeyframe> = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            let time = item.get("t").and_then(Value::as_f64).unwrap_or(0.0) as f32;
+            // Formato antigo: o último keyframe só tem o tempo e o valor vem do `e` anterior
+            let value = match item.get("s") {
+                Some(value) => numbers(value),
+                None => index
+                    .checked_sub(1)
+                    .and_then(|previous| items[previous].get("e"))
+                    .map(numbers)
+                    .unwrap_or_default(),
+            };
+            keyframes.push(LottieKeyframe {
+                time,
+                value,
+                ease_out: item.get("o").and_then(handle),
+                ease_in: item.get("i").and_then(handle),
+                hold: item.get("h").and_then(Value::as_f64).is_some_and(|h| h != 0.0),
+            });
+        }
+        Animated::Keyframes(keyframes)
+    }
+}
+
+impl Animated {
+    /// Verifica se a propriedade tem keyframes
+    pub fn is_animated(&self) -> bool {
+        matches!(self, Animated::Keyframes(keyframes) if keyframes.len() > 1)
+    }
+
+    /// Valor em um quadro
+    pub fn value_at(&self, frame: f32) -> Vec<f32> {
+        let keyframes = match self {
+            Animated::Static(value) => return value.clone(),
+            Animated::Keyframes(keyframes) => keyframes,
+        };
+        let (Some(first), Some(last)) = (keyframes.first(), keyframes.last()) else {
+            return Vec::new();
+        };
+        if frame <= first.time {
+            return first.value.clone();
+        }
+        if frame >= last.time {
+            return last.value.clone();
+        }
+
+        let next = keyframes.partition_point(|k| k.time <= frame);
+        let (current, following) = (&keyframes[next - 1], &keyframes[next]);
+        let span = following.time - current.time;
+        let t = if span > 0.0 { (frame - current.time) / span } else { 1.0 };
+        let t = current.easing().apply(t);
+        current
+            .value
+            .iter()
+            .zip(&following.value)
+            .map(|(a, b)| a + (b - a) * t)
+            .collect()
+    }
+
+    /// Primeiro componente do valor em um quadro
+    pub fn scalar_at(&self, frame: f32) -> f32 {
+        self.value_at(frame).first().copied().unwrap_or(0.0)
+    }
+
+    /// Valor em um quadro como vetor 2D
+    pub fn vec2_at(&self, frame: f32) -> Vec2 {
+        let value = self.value_at(frame);
+        Vec2::new(
+            value.first().copied().unwrap_or(0.0),
+            value.get(1).copied().unwrap_or(0.0),
+        )
+    }
+
+    /// Keyframes da propriedade (vazio se estática)
+    pub fn keyframes(&self) -> &[LottieKeyframe] {
+        match self {
+            Animated::Static(_) => &[],
+            Animated::Keyframes(keyframes) => keyframes,
+        }
+    }
+}
+
+impl LottieKeyframe {
+    /// Easing do trecho que começa neste keyframe
+    pub fn easing(&self) -> Easing {
+        if self.hold {
+            return Easing::Steps(1, StepJump::End);
+        }
+        match (self.ease_out, self.ease_in) {
+            (Some(out), Some(into)) => Easing::cubic_bezier(out.x, out.y, into.x, into.y),
+            _ => Easing::Linear,
+        }
+    }
+}
+
+/// Caminho de Bézier: vértices e tangentes relativas a eles
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct Bezier {
+    #[serde(default)]
+    pub c: bool,
+    #[serde(default)]
+    pub v: Vec<[f32; 2]>,
+    #[serde(default)]
+    pub i: Vec<[f32; 2]>,
+    #[serde(default)]
+    pub o: Vec<[f32; 2]>,
+}
+
+/// Caminho de uma forma (animações de forma usam o primeiro keyframe)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawAnimated")]
+pub(crate) struct AnimatedBezier {
+    pub bezier: Bezier,
+}
+
+impl From<RawAnimated> for AnimatedBezier {
+    fn from(raw: RawAnimated) -> Self {
+        let value = match &raw.k {
+            Value::Array(keyframes) => keyframes.first().and_then(|k| k.get("s")).and_then(|s| match s {
+                Value::Array(shapes) => shapes.first(),
+                shape => Some(shape),
+            }),
+            shape => Some(shape),
+        };
+        Self {
+            bezier: value
+                .and_then(|value| serde_json::from_value(value.clone()).ok())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Números de um valor JSON (número ou lista de números)
+fn numbers(value: &Value) -> Vec<f32> {
+    match value {
+        Value::Number(n) => vec![n.as_f64().unwrap_or(0.0) as f32],
+        Value::Array(items) => items.iter().filter_map(Value::as_f64).map(|n| n as f32).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Tangente de easing `{"x": n | [n], "y": n | [n]}` (usa o primeiro componente)
+fn handle(value: &Value) -> Option<Vec2> {
+    let x = numbers(value.get("x")?).first().copied()?;
+    let y = numbers(value.get("y")?).first().copied()?;
+    Some(Vec2::new(x, y))
+}
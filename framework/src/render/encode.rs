@@ -0,0 +1,116 @@
+//! Gravação de quadros em PNG, GIF animado e APNG
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use super::raster::Frame;
+use super::RenderError;
+
+/// Velocidade da quantização de cores do GIF (1 = melhor, 30 = mais rápido)
+const GIF_QUANTIZATION_SPEED: i32 = 10;
+
+impl Frame {
+    /// Grava o quadro como PNG
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), RenderError> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = png::Encoder::new(file, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Escreve os quadros como GIF animado em loop infinito
+///
+/// Cada quadro é codificado assim que o iterador o entrega, sem guardar os
+/// anteriores. O GIF guarda o atraso de cada quadro em centésimos de segundo;
+/// os atrasos são arredondados de forma acumulada para que a duração total se
+/// mantenha.
+pub fn write_gif(
+    writer: impl Write,
+    frames: impl IntoIterator<Item = Frame>,
+    frame_rate: f32,
+) -> Result<(), RenderError> {
+    let mut frames = frames.into_iter().peekable();
+    let Some(first) = frames.peek() else {
+        return Err(RenderError::NoFrames);
+    };
+    let (width, height) = gif_size(first)?;
+
+    let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    let mut elapsed = 0u32;
+    for (i, mut frame) in frames.enumerate() {
+        // Os pixels do quadro são quantizados no lugar, sem cópia
+        let mut gif_frame =
+            gif::Frame::from_rgba_speed(width, height, &mut frame.pixels, GIF_QUANTIZATION_SPEED);
+        let end = ((i + 1) as f32 * 100.0 / frame_rate).round() as u32;
+        gif_frame.delay = (end - elapsed).min(u16::MAX as u32) as u16;
+        // Áreas transparentes não devem mostrar o quadro anterior
+        gif_frame.dispose = gif::DisposalMethod::Background;
+        elapsed = end;
+        encoder.write_frame(&gif_frame)?;
+    }
+    Ok(())
+}
+
+/// Escreve os quadros como PNG animado (APNG) em loop infinito
+///
+/// O APNG declara o número de quadros no cabeçalho, por isso o iterador
+/// precisa saber o seu tamanho; cada quadro é codificado assim que entregue.
+pub fn write_apng<I>(writer: impl Write, frames: I, frame_rate: f32) -> Result<(), RenderError>
+where
+    I: IntoIterator<Item = Frame>,
+    I::IntoIter: ExactSizeIterator,
+{
+    let mut frames = frames.into_iter().peekable();
+    let count = frames.len() as u32;
+    let Some(first) = frames.peek() else {
+        return Err(RenderError::NoFrames);
+    };
+
+    let mut encoder = png::Encoder::new(writer, first.width, first.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_animated(count, 0)?;
+    // Atraso de 100 / (fps * 100) segundos, com precisão de centésimos de quadro
+    let denominator = (frame_rate * 100.0).round().clamp(1.0, u16::MAX as f32) as u16;
+    encoder.set_frame_delay(100, denominator)?;
+
+    let mut writer = encoder.write_header()?;
+    for frame in frames {
+        writer.write_image_data(&frame.pixels)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Grava os quadros como GIF animado
+pub(crate) fn save_gif(
+    path: &Path,
+    frames: impl IntoIterator<Item = Frame>,
+    frame_rate: f32,
+) -> Result<(), RenderError> {
+    write_gif(BufWriter::new(File::create(path)?), frames, frame_rate)
+}
+
+/// Grava os quadros como APNG
+pub(crate) fn save_apng<I>(path: &Path, frames: I, frame_rate: f32) -> Result<(), RenderError>
+where
+    I: IntoIterator<Item = Frame>,
+    I::IntoIter: ExactSizeIterator,
+{
+    write_apng(BufWriter::new(File::create(path)?), frames, frame_rate)
+}
+
+/// Tamanho do quadro nos limites do formato GIF
+fn gif_size(frame: &Frame) -> Result<(u16, u16), RenderError> {
+    match (u16::try_from(frame.width), u16::try_from(frame.height)) {
+        (Ok(width), Ok(height)) => Ok((width, height)),
+        _ => Err(RenderError::TooLarge(frame.width, frame.height)),
+    }
+}
@@ -0,0 +1,277 @@
+//! Módulo de Renderização Offline
+//!
+//! Renderiza cenas do Kosmos para imagens sem janela e sem GPU: as meshes 2D
+//! são rasterizadas em software (com super-amostragem para antialiasing) e o
+//! relógio das animações avança quadro a quadro, então o resultado é o mesmo
+//! em qualquer máquina. Os quadros podem ser gravados como sequência de PNG,
+//! GIF animado ou APNG.
+//!
+//! São desenhadas as entidades com `Mesh2d` e `MeshMaterial2d<ColorMaterial>`
+//! ou `MeshMaterial2d<ShapeMaterial>` (incluindo o modo de blend e as cores
+//! por vértice), ordenadas pelo Z. Texturas de `ColorMaterial` são ignoradas.
+//!
+//! # Exemplo de uso:
+//! ```rust
+//! use kosmos_framework::render::prelude::*;
+//!
+//! let mut app = App::new();
+//! app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+//!     .init_asset::<Mesh>()
+//!     .init_asset::<ColorMaterial>()
+//!     .add_plugins(AnimationPlugin)
+//!     .add_systems(Startup, setup_scene);
+//!
+//! let renderer = OfflineRenderer::new(480, 320)
+//!     .frame_rate(30.0)
+//!     .background(Color::BLACK);
+//!
+//! renderer.write_gif(&mut app, 2.0, "preview.gif")?;
+//! renderer.write_apng(&mut app, 2.0, "preview.png")?;
+//! renderer.write_png_sequence(&mut app, 2.0, "frames/")?;
+//! ```
+
+use bevy::prelude::*;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use crate::animations::AnimationClock;
+
+mod encode;
+mod raster;
+
+// Re-exportar os tipos públicos
+pub use encode::{write_apng, write_gif};
+pub use raster::Frame;
+
+/// Renderizador offline de cenas e animações
+#[derive(Debug, Clone)]
+pub struct OfflineRenderer {
+    /// Largura da imagem em pixels
+    pub width: u32,
+    /// Altura da imagem em pixels
+    pub height: u32,
+    /// Quadros por segundo
+    pub frame_rate: f32,
+    /// Região do mundo mostrada na imagem
+    pub view: Rect,
+    /// Cor de fundo (transparente por padrão)
+    pub background: Color,
+    /// Amostras por pixel em cada eixo (1 = sem antialiasing)
+    pub supersampling: u32,
+}
+
+impl OfflineRenderer {
+    /// Cria um renderizador com a resolução dada
+    ///
+    /// A vista padrão é centrada na origem com uma unidade do mundo por pixel,
+    /// como a câmera 2D padrão do Bevy.
+    pub fn new(width: u32, height: u32) -> Self {
+        let size = Vec2::new(width.max(1) as f32, height.max(1) as f32);
+        Self {
+            width: width.max(1),
+            height: height.max(1),
+            frame_rate: 30.0,
+            view: Rect::from_center_size(Vec2::ZERO, size),
+            background: Color::NONE,
+            supersampling: 2,
+        }
+    }
+
+    /// Define os quadros por segundo
+    pub fn frame_rate(mut self, frame_rate: f32) -> Self {
+        self.frame_rate = frame_rate.max(f32::EPSILON);
+        self
+    }
+
+    /// Define a região do mundo mostrada na imagem
+    pub fn view(mut self, view: Rect) -> Self {
+        self.view = view;
+        self
+    }
+
+    /// Centraliza a vista em um ponto, mantendo o tamanho
+    pub fn centered_on(mut self, center: Vec2) -> Self {
+        self.view = Rect::from_center_size(center, self.view.size());
+        self
+    }
+
+    /// Define a cor de fundo
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Define as amostras por pixel em cada eixo
+    pub fn supersampling(mut self, samples: u32) -> Self {
+        self.supersampling = samples.clamp(1, 8);
+        self
+    }
+
+    /// Número de quadros para uma duração em segundos (ao menos um)
+    pub fn frame_count(&self, duration: f32) -> usize {
+        ((duration * self.frame_rate).round() as usize).max(1)
+    }
+
+    /// Renderiza o estado atual do mundo, sem avançar o tempo
+    pub fn render_frame(&self, world: &mut World) -> Frame {
+        raster::render_world(world, self)
+    }
+
+    /// Renderiza `duration` segundos de animação, um quadro por vez
+    ///
+    /// O relógio das animações é trocado por um manual durante a gravação e
+    /// avança exatamente `1 / frame_rate` por quadro; o primeiro quadro mostra
+    /// o tempo zero. Cada quadro só é renderizado quando pedido ao iterador, e
+    /// o relógio anterior é restaurado quando o iterador é descartado.
+    pub fn frames<'a>(&'a self, app: &'a mut App, duration: f32) -> RenderedFrames<'a> {
+        let previous = app.world_mut().remove_resource::<AnimationClock>();
+        app.insert_resource(AnimationClock::manual());
+        RenderedFrames {
+            renderer: self,
+            app,
+            index: 0,
+            count: self.frame_count(duration),
+            previous,
+        }
+    }
+
+    /// Renderiza `duration` segundos de animação e guarda todos os quadros
+    ///
+    /// Para gravar animações longas prefira `frames` ou os métodos `write_*`,
+    /// que não mantêm os quadros na memória.
+    pub fn render(&self, app: &mut App, duration: f32) -> Vec<Frame> {
+        self.frames(app, duration).collect()
+    }
+
+    /// Renderiza e grava um PNG por quadro em `directory` (`frame_00000.png`, ...)
+    pub fn write_png_sequence(
+        &self,
+        app: &mut App,
+        duration: f32,
+        directory: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>, RenderError> {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+
+        let mut paths = Vec::new();
+        for (i, frame) in self.frames(app, duration).enumerate() {
+            let path = directory.join(format!("frame_{i:05}.png"));
+            frame.save_png(&path)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Renderiza e grava um GIF animado
+    pub fn write_gif(&self, app: &mut App, duration: f32, path: impl AsRef<Path>) -> Result<(), RenderError> {
+        encode::save_gif(path.as_ref(), self.frames(app, duration), self.frame_rate)
+    }
+
+    /// Renderiza e grava um PNG animado (APNG)
+    pub fn write_apng(&self, app: &mut App, duration: f32, path: impl AsRef<Path>) -> Result<(), RenderError> {
+        encode::save_apng(path.as_ref(), self.frames(app, duration), self.frame_rate)
+    }
+}
+
+/// Iterador que avança a animação e renderiza um quadro por vez
+///
+/// Criado por `OfflineRenderer::frames`.
+pub struct RenderedFrames<'a> {
+    renderer: &'a OfflineRenderer,
+    app: &'a mut App,
+    /// Índice do próximo quadro
+    index: usize,
+    /// Total de quadros
+    count: usize,
+    /// Relógio restaurado no fim da gravação
+    previous: Option<AnimationClock>,
+}
+
+impl Iterator for RenderedFrames<'_> {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        if self.index >= self.count {
+            return None;
+        }
+        if self.index > 0 {
+            let step = 1.0 / self.renderer.frame_rate;
+            self.app.world_mut().resource_mut::<AnimationClock>().step(step);
+        }
+        self.index += 1;
+        self.app.update();
+        Some(self.renderer.render_frame(self.app.world_mut()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for RenderedFrames<'_> {}
+
+impl Drop for RenderedFrames<'_> {
+    fn drop(&mut self) {
+        let clock = self.previous.take().unwrap_or_default();
+        self.app.insert_resource(clock);
+    }
+}
+
+/// Erro ao gravar quadros renderizados
+#[derive(Debug)]
+pub enum RenderError {
+    /// Falha ao escrever o arquivo
+    Io(std::io::Error),
+    /// Falha ao codificar PNG ou APNG
+    Png(png::EncodingError),
+    /// Falha ao codificar GIF
+    Gif(gif::EncodingError),
+    /// Nenhum quadro para gravar
+    NoFrames,
+    /// Imagem maior que o suportado pelo formato (largura, altura)
+    TooLarge(u32, u32),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::Io(error) => write!(f, "erro ao gravar imagem: {error}"),
+            RenderError::Png(error) => write!(f, "erro ao codificar PNG: {error}"),
+            RenderError::Gif(error) => write!(f, "erro ao codificar GIF: {error}"),
+            RenderError::NoFrames => write!(f, "nenhum quadro para gravar"),
+            RenderError::TooLarge(width, height) => {
+                write!(f, "imagem de {width}x{height} grande demais para o formato")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+impl From<std::io::Error> for RenderError {
+    fn from(error: std::io::Error) -> Self {
+        RenderError::Io(error)
+    }
+}
+
+impl From<png::EncodingError> for RenderError {
+    fn from(error: png::EncodingError) -> Self {
+        RenderError::Png(error)
+    }
+}
+
+impl From<gif::EncodingError> for RenderError {
+    fn from(error: gif::EncodingError) -> Self {
+        RenderError::Gif(error)
+    }
+}
+
+/// Prelude para facilitar imports
+pub mod prelude {
+    pub use super::{
+        OfflineRenderer,
+        RenderedFrames,
+        Frame,
+        RenderError,
+    };
+}
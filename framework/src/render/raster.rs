@@ -0,0 +1,299 @@
+//! Rasterizador em software das meshes 2D
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Mesh2d, PrimitiveTopology, VertexAttributeValues};
+use bevy::sprite::MeshMaterial2d;
+use super::OfflineRenderer;
+use crate::geometrics::{BlendMode, ShapeMaterial};
+
+/// Quadro renderizado em RGBA de 8 bits (sRGB, alpha não pré-multiplicado)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Largura em pixels
+    pub width: u32,
+    /// Altura em pixels
+    pub height: u32,
+    /// Pixels linha a linha, de cima para baixo, 4 bytes por pixel
+    pub pixels: Vec<u8>,
+}
+
+impl Frame {
+    /// Cor de um pixel (`None` fora da imagem)
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let index = ((y * self.width + x) * 4) as usize;
+        self.pixels[index..index + 4].try_into().ok()
+    }
+}
+
+/// Mesh pronta para ser desenhada
+struct DrawItem {
+    /// Profundidade (maior = na frente)
+    depth: f32,
+    /// Triângulos em pixels com a cor pré-multiplicada de cada vértice
+    triangles: Vec<[(Vec2, Vec4); 3]>,
+    blend_mode: BlendMode,
+}
+
+/// Renderiza o estado atual do mundo
+pub(crate) fn render_world(world: &mut World, renderer: &OfflineRenderer) -> Frame {
+    let samples = renderer.supersampling.max(1);
+    let width = renderer.width * samples;
+    let height = renderer.height * samples;
+    let view = renderer.view;
+    let scale = Vec2::new(width as f32, height as f32) / view.size();
+    // Coordenadas do mundo (Y para cima) para pixels (Y para baixo)
+    let to_pixel = |p: Vec3| Vec2::new((p.x - view.min.x) * scale.x, (view.max.y - p.y) * scale.y);
+
+    let mut items = collect_draw_items(world, to_pixel);
+    // Ordem estável: entidades com a mesma profundidade mantêm a ordem de criação
+    items.sort_by(|a, b| a.depth.total_cmp(&b.depth));
+
+    let background = premultiply(renderer.background.to_linear().to_vec4());
+    let mut canvas = vec![background; (width * height) as usize];
+    for item in &items {
+        draw_item(&mut canvas, width, height, item);
+    }
+
+    resolve(&canvas, width, samples, renderer.width, renderer.height)
+}
+
+/// Lê as meshes visíveis do mundo com a transformação e a cor de cada uma
+fn collect_draw_items(world: &mut World, to_pixel: impl Fn(Vec3) -> Vec2) -> Vec<DrawItem> {
+    let mut query = world.query::<(Entity, &Mesh2d)>();
+    let mut entities: Vec<(Entity, Handle<Mesh>)> = query
+        .iter(world)
+        .map(|(entity, mesh)| (entity, mesh.0.clone()))
+        .collect();
+    entities.sort_by_key(|(entity, _)| *entity);
+
+    let Some(meshes) = world.get_resource::<Assets<Mesh>>() else {
+        return Vec::new();
+    };
+
+    let mut items = Vec::new();
+    for (entity, handle) in entities {
+        if is_hidden(world, entity) {
+            continue;
+        }
+        let Some(mesh) = meshes.get(&handle) else {
+            continue;
+        };
+        let Some((color, blend_mode, premultiplied)) = material_color(world, entity) else {
+            continue;
+        };
+        let transform = world_transform(world, entity);
+        let Some(triangles) = mesh_triangles(mesh, &transform, color, premultiplied, &to_pixel) else {
+            continue;
+        };
+
+        items.push(DrawItem {
+            depth: transform.translation().z,
+            triangles,
+            blend_mode,
+        });
+    }
+    items
+}
+
+/// Cor linear, modo de blend e se a cor já é pré-multiplicada
+fn material_color(world: &World, entity: Entity) -> Option<(Vec4, BlendMode, bool)> {
+    if let Some(material) = world.get::<MeshMaterial2d<ShapeMaterial>>(entity) {
+        let material = world.get_resource::<Assets<ShapeMaterial>>()?.get(&material.0)?;
+        return Some((
+            material.color.to_linear().to_vec4(),
+            material.blend_mode,
+            material.premultiplied,
+        ));
+    }
+
+    let material = world.get::<MeshMaterial2d<ColorMaterial>>(entity)?;
+    let material = world.get_resource::<Assets<ColorMaterial>>()?.get(&material.0)?;
+    Some((material.color.to_linear().to_vec4(), BlendMode::Alpha, false))
+}
+
+/// Transformação no mundo calculada pela hierarquia de `Transform`
+///
+/// Não depende da propagação do Bevy, então funciona também sem o
+/// `TransformPlugin` (ex: com `MinimalPlugins`).
+fn world_transform(world: &World, entity: Entity) -> GlobalTransform {
+    let local = world.get::<Transform>(entity).copied().unwrap_or_default();
+    match world.get::<ChildOf>(entity) {
+        Some(child_of) => world_transform(world, child_of.parent()).mul_transform(local),
+        None => GlobalTransform::from(local),
+    }
+}
+
+/// Verifica se a entidade ou algum ancestral está oculto
+fn is_hidden(world: &World, entity: Entity) -> bool {
+    match world.get::<Visibility>(entity) {
+        Some(Visibility::Hidden) => true,
+        Some(Visibility::Visible) => false,
+        _ => world
+            .get::<ChildOf>(entity)
+            .is_some_and(|child_of| is_hidden(world, child_of.parent())),
+    }
+}
+
+/// Triângulos da mesh em pixels (`None` se a mesh não é uma lista de triângulos)
+fn mesh_triangles(
+    mesh: &Mesh,
+    transform: &GlobalTransform,
+    color: Vec4,
+    premultiplied: bool,
+    to_pixel: &impl Fn(Vec3) -> Vec2,
+) -> Option<Vec<[(Vec2, Vec4); 3]>> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return None;
+    };
+    let vertex_colors = match mesh.attribute(Mesh::ATTRIBUTE_COLOR) {
+        Some(VertexAttributeValues::Float32x4(colors)) => Some(colors),
+        _ => None,
+    };
+
+    let vertices: Vec<(Vec2, Vec4)> = positions
+        .iter()
+        .enumerate()
+        .map(|(i, position)| {
+            let point = transform.transform_point(Vec3::from_array(*position));
+            let mut vertex_color = color;
+            if let Some(colors) = vertex_colors {
+                vertex_color *= Vec4::from_array(colors[i]);
+            }
+            if !premultiplied {
+                vertex_color = premultiply(vertex_color);
+            }
+            (to_pixel(point), vertex_color)
+        })
+        .collect();
+
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(indices) => indices.iter().collect(),
+        None => (0..vertices.len()).collect(),
+    };
+
+    Some(
+        indices
+            .chunks_exact(3)
+            .filter(|triangle| triangle.iter().all(|&i| i < vertices.len()))
+            .map(|triangle| [vertices[triangle[0]], vertices[triangle[1]], vertices[triangle[2]]])
+            .collect(),
+    )
+}
+
+/// Desenha uma mesh no canvas
+///
+/// Os triângulos são primeiro escritos em um buffer próprio da mesh, para que
+/// arestas compartilhadas não sejam combinadas duas vezes com o fundo.
+fn draw_item(canvas: &mut [Vec4], width: u32, height: u32, item: &DrawItem) {
+    let (min, max) = item.triangles.iter().flatten().fold(
+        (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+        |(min, max), (p, _)| (min.min(*p), max.max(*p)),
+    );
+    let x0 = min.x.floor().max(0.0) as u32;
+    let y0 = min.y.floor().max(0.0) as u32;
+    let x1 = (max.x.ceil().max(0.0) as u32).min(width);
+    let y1 = (max.y.ceil().max(0.0) as u32).min(height);
+    if x0 >= x1 || y0 >= y1 {
+        return;
+    }
+
+    let layer_width = x1 - x0;
+    let mut layer: Vec<Option<Vec4>> = vec![None; (layer_width * (y1 - y0)) as usize];
+    for triangle in &item.triangles {
+        rasterize_triangle(triangle, x0, y0, x1, y1, |x, y, color| {
+            layer[((y - y0) * layer_width + (x - x0)) as usize] = Some(color);
+        });
+    }
+
+    for y in y0..y1 {
+        for x in x0..x1 {
+            if let Some(color) = layer[((y - y0) * layer_width + (x - x0)) as usize] {
+                let pixel = &mut canvas[(y * width + x) as usize];
+                *pixel = item.blend_mode.blend(color, *pixel);
+            }
+        }
+    }
+}
+
+/// Percorre os pixels cobertos por um triângulo, interpolando a cor
+fn rasterize_triangle(
+    triangle: &[(Vec2, Vec4); 3],
+    x0: u32,
+    y0: u32,
+    x1: u32,
+    y1: u32,
+    mut plot: impl FnMut(u32, u32, Vec4),
+) {
+    let [(a, color_a), (b, color_b), (c, color_c)] = *triangle;
+    let area = edge(a, b, c);
+    if area.abs() < f32::EPSILON {
+        return;
+    }
+
+    let min = a.min(b).min(c);
+    let max = a.max(b).max(c);
+    let tx0 = (min.x.floor().max(x0 as f32) as u32).max(x0);
+    let ty0 = (min.y.floor().max(y0 as f32) as u32).max(y0);
+    let tx1 = (max.x.ceil().max(0.0) as u32).min(x1);
+    let ty1 = (max.y.ceil().max(0.0) as u32).min(y1);
+
+    for y in ty0..ty1 {
+        for x in tx0..tx1 {
+            // Amostra no centro do pixel
+            let p = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let wa = edge(b, c, p) / area;
+            let wb = edge(c, a, p) / area;
+            let wc = edge(a, b, p) / area;
+            if wa >= 0.0 && wb >= 0.0 && wc >= 0.0 {
+                plot(x, y, color_a * wa + color_b * wb + color_c * wc);
+            }
+        }
+    }
+}
+
+/// Função de aresta (duas vezes a área com sinal do triângulo a, b, p)
+fn edge(a: Vec2, b: Vec2, p: Vec2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+fn premultiply(color: Vec4) -> Vec4 {
+    (color.truncate() * color.w).extend(color.w)
+}
+
+/// Reduz o canvas super-amostrado para o tamanho final e converte para sRGB
+fn resolve(canvas: &[Vec4], canvas_width: u32, samples: u32, width: u32, height: u32) -> Frame {
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    let weight = 1.0 / (samples * samples) as f32;
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = Vec4::ZERO;
+            for sy in 0..samples {
+                for sx in 0..samples {
+                    sum += canvas[((y * samples + sy) * canvas_width + x * samples + sx) as usize];
+                }
+            }
+            let color = sum * weight;
+            let straight = if color.w > 0.0 {
+                (color.truncate() / color.w).extend(color.w)
+            } else {
+                Vec4::ZERO
+            };
+            let srgb = Srgba::from(LinearRgba::from_vec4(straight.clamp(Vec4::ZERO, Vec4::ONE)));
+            pixels.extend_from_slice(&srgb.to_u8_array());
+        }
+    }
+
+    Frame {
+        width,
+        height,
+        pixels,
+    }
+}